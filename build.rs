@@ -0,0 +1,105 @@
+//! Generates one test per part of each example fixture.
+//!
+//! Fixtures live in `inputs/examples/dayXX-N.txt` and their expected answers in
+//! `inputs/examples/answers.toml`, using the same TOML subset and parser as
+//! `answers.toml` with one `[dayXX-N]` table per fixture. The tests are written to
+//! `$OUT_DIR/examples.rs` and included by `src/examples.rs`.
+
+// Only the table parser is needed here
+#[allow(dead_code)]
+#[path = "src/answers.rs"]
+mod answers;
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const EXAMPLES_DIR: &str = "inputs/examples";
+const MANIFEST: &str = "answers.toml";
+
+/// Expected answers of both parts, per fixture name ("day01-1").
+type Manifest = BTreeMap<String, [Option<u64>; 2]>;
+
+/// Day number of a fixture name, if it is one.
+fn fixture_day(name: &str) -> Option<u8> {
+    let (day, example) = name.strip_prefix("day")?.split_once('-')?;
+    example.parse::<u32>().ok()?;
+    day.parse().ok()
+}
+
+fn parse_manifest(text: &str) -> Result<Manifest, String> {
+    answers::parse_tables(text, "[dayXX-N]", |name| {
+        fixture_day(name).map(|_| name.to_string())
+    })
+}
+
+/// Names of the fixture files in `dir`, without their extension.
+fn fixtures(dir: &Path) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let name = path.file_name()?.to_str()?.strip_suffix(".txt")?;
+            fixture_day(name).map(|_| name.to_string())
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+fn main() {
+    let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let dir = root.join(EXAMPLES_DIR);
+    let manifest_path = dir.join(MANIFEST);
+    println!("cargo::rerun-if-changed={}", dir.display());
+    println!("cargo::rerun-if-changed={}", manifest_path.display());
+
+    let manifest = match fs::read_to_string(&manifest_path) {
+        Ok(text) => {
+            parse_manifest(&text).unwrap_or_else(|e| panic!("{}: {}", manifest_path.display(), e))
+        }
+        Err(_) => Manifest::new(),
+    };
+    let fixtures = fixtures(&dir);
+
+    if let Some(name) = manifest.keys().find(|name| !fixtures.contains(name)) {
+        panic!(
+            "{}: [{}] has no {}/{}.txt fixture",
+            manifest_path.display(),
+            name,
+            EXAMPLES_DIR,
+            name
+        );
+    }
+
+    let mut tests = String::new();
+    for name in &fixtures {
+        let Some(answers) = manifest.get(name) else {
+            println!(
+                "cargo::warning={}/{}.txt has no answers in {}, no test generated",
+                EXAMPLES_DIR, name, MANIFEST
+            );
+            continue;
+        };
+        let path = dir.join(format!("{}.txt", name));
+        for (part, expected) in answers.iter().enumerate() {
+            let Some(expected) = expected else {
+                continue;
+            };
+            tests.push_str(&format!(
+                "#[test]\nfn {ident}_part{part}() {{\n    check({day}, {part}, include_str!({path:?}), {expected});\n}}\n\n",
+                ident = name.replace('-', "_"),
+                part = part + 1,
+                day = fixture_day(name).unwrap(),
+                path = path,
+                expected = expected,
+            ));
+        }
+    }
+
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap()).join("examples.rs");
+    fs::write(&out, tests).unwrap_or_else(|e| panic!("failed to write {}: {}", out.display(), e));
+}
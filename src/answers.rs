@@ -0,0 +1,231 @@
+//! Expected answers for the real inputs, stored in `answers.toml`.
+//!
+//! Only the subset of TOML written by `Answers::to_toml` is understood:
+//!
+//! ```toml
+//! [day01]
+//! part1 = 962
+//! part2 = 5782
+//! ```
+//!
+//! `build.rs` includes this file to read the example manifest with the same parser,
+//! so it must only depend on `std`.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Default location of the answers file, relative to the repository root.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Expected answers of both parts, per day.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    days: BTreeMap<u8, [Option<u64>; 2]>,
+}
+
+/// An answer that differs from the recorded one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub day: u8,
+    pub part: u8,
+    pub expected: u64,
+    pub actual: Option<u64>,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.actual {
+            Some(actual) => write!(
+                f,
+                "day {:02} part {}: expected {}, got {}",
+                self.day, self.part, self.expected, actual
+            ),
+            None => write!(
+                f,
+                "day {:02} part {}: expected {}, got nothing",
+                self.day, self.part, self.expected
+            ),
+        }
+    }
+}
+
+/// Parses `[table]` headers followed by `partN = answer` lines, with `#` comments.
+///
+/// `key` turns a table name into its key, or rejects it; `shape` describes the
+/// expected names in error messages, e.g. `[dayXX]`.
+pub fn parse_tables<K: Ord + Clone>(
+    text: &str,
+    shape: &str,
+    key: impl Fn(&str) -> Option<K>,
+) -> Result<BTreeMap<K, [Option<u64>; 2]>, String> {
+    let mut tables: BTreeMap<K, [Option<u64>; 2]> = BTreeMap::new();
+    let mut table = None;
+
+    for (i, line) in text.lines().enumerate() {
+        let error = |message: &str| format!("line {}: {}", i + 1, message);
+        let line = line.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(header) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            let name = key(header).ok_or_else(|| error(&format!("expected a {} table", shape)))?;
+            tables.entry(name.clone()).or_default();
+            table = Some(name);
+            continue;
+        }
+
+        let table = table
+            .as_ref()
+            .ok_or_else(|| error(&format!("answer outside of a {} table", shape)))?;
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| error("expected partN = answer"))?;
+        let part = match key.trim() {
+            "part1" => 0,
+            "part2" => 1,
+            _ => return Err(error("expected part1 or part2")),
+        };
+        let value = value
+            .trim()
+            .parse()
+            .map_err(|_| error("expected an unsigned integer"))?;
+        tables.get_mut(table).unwrap()[part] = Some(value);
+    }
+
+    Ok(tables)
+}
+
+impl Answers {
+    /// Parses the content of an answers file.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let days = parse_tables(text, "[dayXX]", |name| {
+            name.strip_prefix("day")?.parse().ok()
+        })?;
+        Ok(Answers { days })
+    }
+
+    /// Loads an answers file; a missing file holds no answers.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|e| format!("{}: {}", path.display(), e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(format!("failed to read {}: {}", path.display(), e)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_toml())
+    }
+
+    /// Expected answers of a day (`None` for unknown parts).
+    pub fn get(&self, day: u8) -> [Option<u64>; 2] {
+        self.days.get(&day).copied().unwrap_or_default()
+    }
+
+    /// Records the answers of a day, keeping previous answers for parts without one.
+    pub fn record(&mut self, day: u8, answers: [Option<u64>; 2]) {
+        let entry = self.days.entry(day).or_default();
+        for (expected, actual) in entry.iter_mut().zip(answers) {
+            if actual.is_some() {
+                *expected = actual;
+            }
+        }
+    }
+
+    /// Compares the answers of a day with the expected ones.
+    pub fn check(&self, day: u8, actual: [Option<u64>; 2]) -> Vec<Mismatch> {
+        self.get(day)
+            .iter()
+            .zip(actual)
+            .enumerate()
+            .filter_map(|(i, (&expected, actual))| {
+                let expected = expected?;
+                (Some(expected) != actual).then_some(Mismatch {
+                    day,
+                    part: i as u8 + 1,
+                    expected,
+                    actual,
+                })
+            })
+            .collect()
+    }
+
+    pub fn to_toml(&self) -> String {
+        let mut out = String::from(
+            "# Expected answers for the real inputs, checked by the runner and `cargo test`.\n",
+        );
+        for (day, parts) in &self.days {
+            out.push_str(&format!("\n[day{:02}]\n", day));
+            for (i, answer) in parts.iter().enumerate() {
+                if let Some(answer) = answer {
+                    out.push_str(&format!("part{} = {}\n", i + 1, answer));
+                }
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DAYS, InputError, InputSource};
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_parse_roundtrip() {
+        let text = "# comment\n[day01]\npart1 = 3\npart2 = 6 # inline\n\n[day12]\npart1 = 2\n";
+        let answers = Answers::parse(text).unwrap();
+        assert_eq!(answers.get(1), [Some(3), Some(6)]);
+        assert_eq!(answers.get(12), [Some(2), None]);
+        assert_eq!(answers.get(5), [None, None]);
+        assert_eq!(Answers::parse(&answers.to_toml()).unwrap(), answers);
+
+        assert_eq!(
+            Answers::parse("[day01]\npart3 = 1").unwrap_err(),
+            "line 2: expected part1 or part2"
+        );
+        assert_eq!(
+            Answers::parse("part1 = 1").unwrap_err(),
+            "line 1: answer outside of a [dayXX] table"
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse("[day01]\npart1 = 3\n").unwrap();
+        assert!(answers.check(1, [Some(3), Some(7)]).is_empty());
+        assert_eq!(
+            answers.check(1, [Some(4), None])[0].to_string(),
+            "day 01 part 1: expected 3, got 4"
+        );
+    }
+
+    /// Every day with an input file must still produce its recorded answers.
+    #[test]
+    fn test_real_inputs() {
+        let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let expected = Answers::load(&root.join(ANSWERS_FILE)).unwrap();
+
+        let mut mismatches = Vec::new();
+        for day in DAYS {
+            let path = root.join(format!("inputs/day{:02}.txt", day.number));
+            let raw = match InputSource::File(path).read() {
+                Ok(raw) => raw,
+                // Not downloaded yet, e.g. a freshly scaffolded day
+                Err(InputError::Missing { .. } | InputError::Empty { .. }) => continue,
+                Err(e) => panic!("day {:02}: {}", day.number, e),
+            };
+            let parsed = day.parse(&raw).unwrap();
+            let actual = [Some(parsed.part1()), parsed.part2()];
+            mismatches.extend(expected.check(day.number, actual));
+        }
+
+        let report: Vec<String> = mismatches.iter().map(|m| m.to_string()).collect();
+        assert!(report.is_empty(), "{}", report.join("\n"));
+    }
+}
@@ -1,128 +1,7 @@
-use advent_of_code_2025::read_split;
-use std::time::Instant;
+use advent_of_code_2025::days::day12::Day12;
+use advent_of_code_2025::run;
+use std::process::ExitCode;
 
-/// Parses input into shape areas and regions (width, height, counts per shape).
-fn parse_input(input: &[String]) -> (Vec<u64>, Vec<(u64, u64, Vec<u64>)>) {
-    let mut shape_areas = Vec::new();
-    let mut regions = Vec::new();
-
-    for chunk in input.split(|line| line.trim().is_empty()) {
-        if chunk.is_empty() {
-            continue;
-        }
-
-        // Check for shape definition (e.g., "0:")
-        if let Some(header) = chunk[0].trim().strip_suffix(':') {
-            if header.parse::<usize>().is_ok() {
-                let area: u64 = chunk[1..]
-                    .iter()
-                    .map(|line| line.chars().filter(|&c| c == '#').count() as u64)
-                    .sum();
-                shape_areas.push(area);
-                continue;
-            }
-        }
-
-        // Parse regions in the chunk
-        for line in chunk {
-            if let Some((dims, counts_str)) = line.split_once(':') {
-                if let Some((w, h)) = dims.split_once('x') {
-                    if let (Ok(w), Ok(h)) = (w.parse::<u64>(), h.parse::<u64>()) {
-                        let counts: Vec<u64> = counts_str
-                            .split_whitespace()
-                            .filter_map(|s| s.parse().ok())
-                            .collect();
-                        regions.push((w, h, counts));
-                    }
-                }
-            }
-        }
-    }
-
-    (shape_areas, regions)
-}
-
-/// Counts regions where the total area of presents fits in the region.
-fn part1(input: &[String]) -> u64 {
-    let (shape_areas, regions) = parse_input(input);
-    let mut count = 0;
-
-    for (w, h, counts) in regions {
-        let region_area = w * h;
-        let mut present_area = 0;
-
-        for (i, &c) in counts.iter().enumerate() {
-            present_area += c * shape_areas[i];
-        }
-
-        if present_area <= region_area {
-            count += 1;
-        }
-    }
-
-    count
-}
-
-fn main() {
-    let input = read_split(12, "\n");
-
-    println!("Day 12 Results: ⭐⭐");
-
-    let start = Instant::now();
-    let result1 = part1(&input);
-    let duration1 = start.elapsed();
-    println!("Part 1: {} (took {:?})", result1, duration1);
-
-    println!("Total: {:?}", duration1);
-
-    println!("\n--- Résumé des solutions ---");
-    println!("Part 1: Compter les régions où la surface totale des cadeaux tient dans la région");
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const EXAMPLE: &str = "0:
-###
-##.
-##.
-
-1:
-###
-##.
-.##
-
-2:
-.##
-###
-##.
-
-3:
-##.
-###
-##.
-
-4:
-###
-#..
-###
-
-5:
-###
-.#.
-###
-
-4x4: 0 0 0 0 2 0
-12x5: 1 0 1 0 2 2
-12x5: 1 0 1 0 3 2";
-
-    #[test]
-    fn test_part1() {
-        let input: Vec<String> = EXAMPLE.lines().map(|s| s.to_string()).collect();
-        // L'approche par somme des aires donne 3, mais l'énoncé dit 2
-        // (la dernière région ne peut pas physiquement contenir tous les cadeaux)
-        // Pour l'input réel, la somme des aires suffit
-        assert_eq!(part1(&input), 3);
-    }
+fn main() -> ExitCode {
+    run::<Day12>()
 }
@@ -0,0 +1,126 @@
+//! Heap usage of each step, measured by a counting global allocator.
+//!
+//! The allocator is only installed with the `alloc-stats` feature, since counting
+//! every allocation slows the solutions down a little:
+//!
+//! ```bash
+//! cargo run --release --features alloc-stats --bin runner
+//! ```
+
+/// Heap usage of one step.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Highest number of live heap bytes above the level at the start of the step.
+    pub peak: u64,
+    /// Bytes allocated during the step, reallocations included.
+    pub bytes: u64,
+    /// Number of allocations and reallocations.
+    pub count: u64,
+}
+
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use super::AllocStats;
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    static LIVE: AtomicU64 = AtomicU64::new(0);
+    static PEAK: AtomicU64 = AtomicU64::new(0);
+    static BYTES: AtomicU64 = AtomicU64::new(0);
+    static COUNT: AtomicU64 = AtomicU64::new(0);
+
+    /// The system allocator, counting what goes through it.
+    struct Counting;
+
+    fn allocated(size: usize) {
+        let live = LIVE.fetch_add(size as u64, Ordering::Relaxed) + size as u64;
+        PEAK.fetch_max(live, Ordering::Relaxed);
+        BYTES.fetch_add(size as u64, Ordering::Relaxed);
+        COUNT.fetch_add(1, Ordering::Relaxed);
+    }
+
+    // SAFETY: every call is forwarded to the system allocator unchanged.
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            // SAFETY: same contract as `GlobalAlloc::alloc`.
+            let ptr = unsafe { System.alloc(layout) };
+            if !ptr.is_null() {
+                allocated(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            // SAFETY: same contract as `GlobalAlloc::alloc_zeroed`.
+            let ptr = unsafe { System.alloc_zeroed(layout) };
+            if !ptr.is_null() {
+                allocated(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            // SAFETY: same contract as `GlobalAlloc::dealloc`.
+            unsafe { System.dealloc(ptr, layout) };
+            LIVE.fetch_sub(layout.size() as u64, Ordering::Relaxed);
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            // SAFETY: same contract as `GlobalAlloc::realloc`.
+            let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+            if !new_ptr.is_null() {
+                LIVE.fetch_sub(layout.size() as u64, Ordering::Relaxed);
+                allocated(new_size);
+            }
+            new_ptr
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+
+    /// Runs `f`, measuring its heap usage.
+    pub fn track<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+        let live = LIVE.load(Ordering::Relaxed);
+        PEAK.store(live, Ordering::Relaxed);
+        let (bytes, count) = (BYTES.load(Ordering::Relaxed), COUNT.load(Ordering::Relaxed));
+
+        let value = f();
+
+        let stats = AllocStats {
+            peak: PEAK.load(Ordering::Relaxed).saturating_sub(live),
+            bytes: BYTES.load(Ordering::Relaxed).saturating_sub(bytes),
+            count: COUNT.load(Ordering::Relaxed).saturating_sub(count),
+        };
+        (value, Some(stats))
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+pub use counting::track;
+
+/// Runs `f`; heap usage is only measured with the `alloc-stats` feature.
+#[cfg(not(feature = "alloc-stats"))]
+pub fn track<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    (f(), None)
+}
+
+#[cfg(all(test, feature = "alloc-stats"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_track() {
+        let (_, stats) = track(|| {
+            let first = vec![0u8; 1000];
+            drop(first);
+            let second: Vec<u8> = Vec::with_capacity(600);
+            second
+        });
+        let stats = stats.unwrap();
+        // Other tests may allocate concurrently, so only lower bounds hold
+        assert!(stats.peak >= 1000, "{:?}", stats);
+        assert!(stats.bytes >= 1600);
+        assert!(stats.count >= 2);
+    }
+}
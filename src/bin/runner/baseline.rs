@@ -0,0 +1,430 @@
+//! Saved benchmark runs and the detection of regressions against them.
+//!
+//! A baseline holds the statistics of every step of a benchmark run, one
+//! `day step samples min median mean stddev outliers` line per step (durations in
+//! nanoseconds). A step regressed when its mean got slower by more than the threshold
+//! and Welch's t-test says the difference is significant.
+
+use crate::DayResult;
+use crate::bench::Stats;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Directory holding the baselines, one `NAME.txt` file each.
+pub const BASELINE_DIR: &str = "target/baselines";
+
+/// Default relative change (in percent) below which differences are ignored.
+pub const DEFAULT_THRESHOLD: f64 = 5.0;
+
+/// Significance level of the t-test.
+const ALPHA: f64 = 0.05;
+
+/// Statistics of each step of a benchmark run, keyed by day and step name.
+#[derive(Debug, Default, PartialEq)]
+pub struct Baseline {
+    steps: BTreeMap<(u8, String), Stats>,
+}
+
+/// Path of the baseline called `name`, which must be a plain file name so that it
+/// stays inside `BASELINE_DIR`.
+pub fn path(name: &str) -> Result<PathBuf, String> {
+    if name.is_empty() || name == "." || name.contains("..") || name.contains(['/', '\\']) {
+        return Err(format!(
+            "invalid baseline name {:?}, expected a name without path separators or ..",
+            name
+        ));
+    }
+    Ok(PathBuf::from(BASELINE_DIR).join(format!("{}.txt", name)))
+}
+
+/// Steps of a result with their statistics, in display order.
+fn steps(result: &DayResult) -> [(&'static str, Option<Stats>); 3] {
+    [
+        ("parse", result.parse),
+        ("part1", result.part1),
+        ("part2", result.part2),
+    ]
+}
+
+impl Baseline {
+    /// Baseline of the days that ran to completion.
+    pub fn from_results(results: &[DayResult]) -> Self {
+        let mut baseline = Baseline::default();
+        for result in results.iter().filter(|r| r.status.ran()) {
+            for (step, stats) in steps(result) {
+                if let Some(stats) = stats {
+                    baseline.steps.insert((result.day, step.to_string()), stats);
+                }
+            }
+        }
+        baseline
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut baseline = Baseline::default();
+        for (i, line) in text.lines().enumerate() {
+            let invalid = || format!("line {}: invalid baseline entry", i + 1);
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [day, step, rest @ ..] = &fields[..] else {
+                return Err(invalid());
+            };
+            let numbers: Vec<u64> = rest
+                .iter()
+                .map(|f| f.parse())
+                .collect::<Result<_, _>>()
+                .map_err(|_| invalid())?;
+            let [samples, min, median, mean, stddev, outliers] = numbers[..] else {
+                return Err(invalid());
+            };
+            let stats = Stats {
+                samples: samples as usize,
+                min: Duration::from_nanos(min),
+                median: Duration::from_nanos(median),
+                mean: Duration::from_nanos(mean),
+                stddev: Duration::from_nanos(stddev),
+                outliers: outliers as usize,
+            };
+            let day = day.parse().map_err(|_| invalid())?;
+            baseline.steps.insert((day, step.to_string()), stats);
+        }
+        Ok(baseline)
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut out = String::new();
+        for ((day, step), s) in &self.steps {
+            writeln!(
+                out,
+                "{} {} {} {} {} {} {} {}",
+                day,
+                step,
+                s.samples,
+                s.min.as_nanos(),
+                s.median.as_nanos(),
+                s.mean.as_nanos(),
+                s.stddev.as_nanos(),
+                s.outliers
+            )
+            .unwrap();
+        }
+        out
+    }
+
+    /// Baseline from the `--format csv` output of a runner.
+    pub fn from_csv(text: &str) -> Result<Self, String> {
+        let mut lines = text.lines();
+        let header = csv_fields(lines.next().unwrap_or_default());
+        let column = |name: &str| {
+            header
+                .iter()
+                .position(|h| h == name)
+                .ok_or_else(|| format!("CSV column {} not found", name))
+        };
+        let [day, step, samples, min, median, mean, stddev, outliers] = [
+            "day",
+            "step",
+            "samples",
+            "min_ns",
+            "median_ns",
+            "mean_ns",
+            "stddev_ns",
+            "outliers",
+        ]
+        .map(column);
+        let (day, step) = (day?, step?);
+        let numbers = [samples?, min?, median?, mean?, stddev?, outliers?];
+
+        let mut baseline = Baseline::default();
+        for (i, line) in lines.enumerate() {
+            let fields = csv_fields(line);
+            let field = |column: usize| fields.get(column).map_or("", String::as_str);
+            if field(step).is_empty() {
+                // Days that did not run have no steps
+                continue;
+            }
+            let invalid = || format!("CSV line {}: invalid entry", i + 2);
+            let values: Vec<u64> = numbers
+                .iter()
+                .map(|&column| field(column).parse())
+                .collect::<Result<_, _>>()
+                .map_err(|_| invalid())?;
+            let stats = Stats {
+                samples: values[0] as usize,
+                min: Duration::from_nanos(values[1]),
+                median: Duration::from_nanos(values[2]),
+                mean: Duration::from_nanos(values[3]),
+                stddev: Duration::from_nanos(values[4]),
+                outliers: values[5] as usize,
+            };
+            let day = field(day).parse().map_err(|_| invalid())?;
+            baseline.steps.insert((day, field(step).to_string()), stats);
+        }
+        Ok(baseline)
+    }
+
+    /// Sum of the median time of each step of a day, if it was measured.
+    pub fn day_total(&self, day: u8) -> Option<Duration> {
+        let medians: Vec<Duration> = self
+            .steps
+            .range((day, String::new())..)
+            .take_while(|((d, _), _)| *d == day)
+            .map(|(_, stats)| stats.median)
+            .collect();
+        (!medians.is_empty()).then(|| medians.iter().sum())
+    }
+
+    /// Loads the baseline called `name`.
+    pub fn load(name: &str) -> Result<Self, String> {
+        let path = path(name)?;
+        let text = fs::read_to_string(&path)
+            .map_err(|e| format!("failed to read baseline {}: {}", path.display(), e))?;
+        Self::parse(&text).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// Saves this baseline under `name`, replacing any previous one.
+    pub fn save(&self, name: &str) -> Result<PathBuf, String> {
+        let path = path(name)?;
+        fs::create_dir_all(BASELINE_DIR)
+            .and_then(|()| fs::write(&path, self.to_text()))
+            .map_err(|e| format!("failed to write baseline {}: {}", path.display(), e))?;
+        Ok(path)
+    }
+}
+
+/// How a step compares with the baseline.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Improved,
+    Unchanged,
+    Regressed,
+}
+
+/// Comparison of one step with the same step in the baseline.
+#[derive(Debug)]
+pub struct Comparison {
+    pub day: u8,
+    pub step: &'static str,
+    pub baseline: Stats,
+    pub current: Stats,
+    /// Relative change of the mean, in percent (positive when slower).
+    pub change: f64,
+    /// Two-sided p-value of Welch's t-test on the means.
+    pub p_value: f64,
+    pub verdict: Verdict,
+}
+
+/// Compares every step present in both the results and the baseline.
+pub fn compare(baseline: &Baseline, results: &[DayResult], threshold: f64) -> Vec<Comparison> {
+    let mut comparisons = Vec::new();
+    for result in results.iter().filter(|r| r.status.ran()) {
+        for (step, current) in steps(result) {
+            let Some(current) = current else { continue };
+            let Some(&old) = baseline.steps.get(&(result.day, step.to_string())) else {
+                continue;
+            };
+
+            let old_mean = nanos(old.mean);
+            let change = if old_mean > 0.0 {
+                (nanos(current.mean) - old_mean) / old_mean * 100.0
+            } else {
+                0.0
+            };
+            let p_value = welch_p_value(&old, &current);
+            let verdict = if p_value >= ALPHA || change.abs() <= threshold {
+                Verdict::Unchanged
+            } else if change > 0.0 {
+                Verdict::Regressed
+            } else {
+                Verdict::Improved
+            };
+
+            comparisons.push(Comparison {
+                day: result.day,
+                step,
+                baseline: old,
+                current,
+                change,
+                p_value,
+                verdict,
+            });
+        }
+    }
+    comparisons
+}
+
+/// Splits a CSV line into fields, handling quoted fields.
+fn csv_fields(line: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                fields.last_mut().unwrap().push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(String::new()),
+            c => fields.last_mut().unwrap().push(c),
+        }
+    }
+    fields
+}
+
+fn nanos(duration: Duration) -> f64 {
+    duration.as_nanos() as f64
+}
+
+/// Two-sided p-value of Welch's t-test for the difference of two means.
+fn welch_p_value(a: &Stats, b: &Stats) -> f64 {
+    let (na, nb) = (a.samples as f64, b.samples as f64);
+    if na < 2.0 || nb < 2.0 {
+        return 1.0;
+    }
+    let va = nanos(a.stddev).powi(2) / na;
+    let vb = nanos(b.stddev).powi(2) / nb;
+    let diff = nanos(b.mean) - nanos(a.mean);
+    if va + vb == 0.0 {
+        return if diff == 0.0 { 1.0 } else { 0.0 };
+    }
+
+    let t = diff / (va + vb).sqrt();
+    let df = (va + vb).powi(2) / (va.powi(2) / (na - 1.0) + vb.powi(2) / (nb - 1.0));
+    incomplete_beta(df / 2.0, 0.5, df / (df + t * t))
+}
+
+/// Natural logarithm of the gamma function (Lanczos approximation).
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 6] = [
+        76.18009172947146,
+        -86.50532032941677,
+        24.01409824083091,
+        -1.231739572450155,
+        0.1208650973866179e-2,
+        -0.5395239384953e-5,
+    ];
+    let tmp = x + 5.5 - (x + 0.5) * (x + 5.5).ln();
+    let series: f64 = COEFFICIENTS
+        .iter()
+        .enumerate()
+        .map(|(i, c)| c / (x + 1.0 + i as f64))
+        .sum();
+    -tmp + (2.5066282746310005 * (1.000000000190015 + series) / x).ln()
+}
+
+/// Regularized incomplete beta function I_x(a, b).
+fn incomplete_beta(a: f64, b: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+    let front =
+        (ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln()).exp();
+    // The continued fraction converges quickly only on one side of the mean
+    if x < (a + 1.0) / (a + b + 2.0) {
+        front * beta_fraction(a, b, x) / a
+    } else {
+        1.0 - front * beta_fraction(b, a, 1.0 - x) / b
+    }
+}
+
+/// Continued fraction of the incomplete beta function (modified Lentz's method).
+fn beta_fraction(a: f64, b: f64, x: f64) -> f64 {
+    const TINY: f64 = 1e-300;
+    let mut c = 1.0;
+    let mut d = 1.0 - (a + b) * x / (a + 1.0);
+    if d.abs() < TINY {
+        d = TINY;
+    }
+    d = 1.0 / d;
+    let mut h = d;
+
+    for m in 1..=200 {
+        let m = m as f64;
+        for numerator in [
+            m * (b - m) * x / ((a + 2.0 * m - 1.0) * (a + 2.0 * m)),
+            -(a + m) * (a + b + m) * x / ((a + 2.0 * m) * (a + 2.0 * m + 1.0)),
+        ] {
+            d = 1.0 + numerator * d;
+            if d.abs() < TINY {
+                d = TINY;
+            }
+            c = 1.0 + numerator / c;
+            if c.abs() < TINY {
+                c = TINY;
+            }
+            d = 1.0 / d;
+            h *= d * c;
+        }
+        if (d * c - 1.0).abs() < 1e-12 {
+            break;
+        }
+    }
+    h
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(samples: usize, mean_us: u64, stddev_us: u64) -> Stats {
+        Stats {
+            samples,
+            min: Duration::from_micros(mean_us - stddev_us),
+            median: Duration::from_micros(mean_us),
+            mean: Duration::from_micros(mean_us),
+            stddev: Duration::from_micros(stddev_us),
+            outliers: 0,
+        }
+    }
+
+    #[test]
+    fn test_welch_p_value() {
+        // Same means: no evidence of a difference
+        let p = welch_p_value(&stats(30, 100, 10), &stats(30, 100, 10));
+        assert!((p - 1.0).abs() < 1e-9, "p = {}", p);
+        // t = 2 with 50 samples each and equal variances: df = 98, p ≈ 0.0483
+        let p = welch_p_value(&stats(50, 100, 10), &stats(50, 104, 10));
+        assert!((p - 0.0483).abs() < 1e-3, "p = {}", p);
+        // Far apart with little noise
+        assert!(welch_p_value(&stats(100, 100, 1), &stats(100, 120, 1)) < 1e-6);
+    }
+
+    #[test]
+    fn test_baseline_roundtrip() {
+        let mut baseline = Baseline::default();
+        baseline
+            .steps
+            .insert((2, "part1".to_string()), stats(100, 110_000, 500));
+        assert_eq!(Baseline::parse(&baseline.to_text()).unwrap(), baseline);
+        assert!(Baseline::parse("2 part1 100 1 2").is_err());
+    }
+
+    #[test]
+    fn test_path() {
+        assert_eq!(
+            path("before").unwrap(),
+            PathBuf::from(BASELINE_DIR).join("before.txt")
+        );
+        for name in ["", "..", "../x", "a/b", "/tmp/x", "a\\b"] {
+            assert!(path(name).is_err(), "{:?}", name);
+        }
+    }
+
+    #[test]
+    fn test_from_csv() {
+        let csv = "day,title,status,step,answer,samples,min_ns,median_ns,mean_ns,stddev_ns,outliers,error\n\
+                   1,\"Secret, \"\"Entrance\"\"\",success,parse,,5,10,20,20,1,0,\n\
+                   1,\"Secret, \"\"Entrance\"\"\",success,part1,3,5,30,40,40,1,0,\n\
+                   2,Gift Shop,missing,,,,,,,,,\n";
+        let baseline = Baseline::from_csv(csv).unwrap();
+        assert_eq!(baseline.day_total(1), Some(Duration::from_nanos(60)));
+        assert_eq!(baseline.day_total(2), None);
+        assert!(Baseline::from_csv("day,step\n").is_err());
+    }
+}
@@ -0,0 +1,144 @@
+//! Repeated measurements and their summary statistics.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Minimum number of samples taken, even when the time budget is exhausted.
+const MIN_SAMPLES: usize = 5;
+
+/// How each step of a day is measured in benchmark mode.
+#[derive(Clone, Copy, Debug)]
+pub struct BenchConfig {
+    /// Untimed runs before sampling starts.
+    pub warmup: u32,
+    /// Maximum number of timed runs.
+    pub samples: u32,
+    /// Sampling stops once this much time is spent (after `MIN_SAMPLES`).
+    pub budget: Duration,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            warmup: 3,
+            samples: 100,
+            budget: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Summary of the timings of one step.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+    /// Samples outside the Tukey fences (1.5 × IQR beyond the quartiles).
+    pub outliers: usize,
+}
+
+impl Stats {
+    /// Stats of a single measurement.
+    pub fn single(duration: Duration) -> Self {
+        Self::from_samples(&mut [duration])
+    }
+
+    /// Summarises a non-empty set of samples (sorted in place).
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarise");
+        samples.sort_unstable();
+
+        let n = samples.len();
+        let nanos: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        let variance = if n > 1 {
+            nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0.0
+        };
+        let median = if n % 2 == 1 {
+            nanos[n / 2]
+        } else {
+            (nanos[n / 2 - 1] + nanos[n / 2]) / 2.0
+        };
+
+        let (q1, q3) = (nanos[n / 4], nanos[(3 * n) / 4]);
+        let fence = 1.5 * (q3 - q1);
+        let outliers = nanos
+            .iter()
+            .filter(|&&x| x < q1 - fence || x > q3 + fence)
+            .count();
+
+        Stats {
+            samples: n,
+            min: samples[0],
+            median: Duration::from_nanos(median as u64),
+            mean: Duration::from_nanos(mean as u64),
+            stddev: Duration::from_nanos(variance.sqrt() as u64),
+            outliers,
+        }
+    }
+}
+
+/// Runs `f` repeatedly according to `config` and summarises the timings.
+pub fn measure<T>(config: &BenchConfig, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..config.warmup {
+        black_box(f());
+    }
+
+    let mut samples = Vec::with_capacity(config.samples as usize);
+    let start = Instant::now();
+    while samples.len() < config.samples.max(1) as usize
+        && (samples.len() < MIN_SAMPLES || start.elapsed() < config.budget)
+    {
+        let sample_start = Instant::now();
+        black_box(f());
+        samples.push(sample_start.elapsed());
+    }
+
+    Stats::from_samples(&mut samples)
+}
+
+/// Parses a duration such as "500ms", "2s" or "1.5s".
+pub fn parse_duration(s: &str) -> Option<Duration> {
+    let (value, unit) = s.split_at(s.find(|c: char| !c.is_ascii_digit() && c != '.')?);
+    let value: f64 = value.parse().ok()?;
+    let seconds = match unit {
+        "us" | "µs" => value / 1e6,
+        "ms" => value / 1e3,
+        "s" => value,
+        _ => return None,
+    };
+    Duration::try_from_secs_f64(seconds).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let mut samples: Vec<Duration> = [10, 12, 11, 13, 12, 50]
+            .iter()
+            .map(|&us| Duration::from_micros(us))
+            .collect();
+        let stats = Stats::from_samples(&mut samples);
+        assert_eq!(stats.samples, 6);
+        assert_eq!(stats.min, Duration::from_micros(10));
+        assert_eq!(stats.median, Duration::from_micros(12));
+        assert_eq!(stats.mean, Duration::from_micros(18));
+        assert_eq!(stats.outliers, 1);
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("500ms"), Some(Duration::from_millis(500)));
+        assert_eq!(parse_duration("1.5s"), Some(Duration::from_millis(1500)));
+        assert_eq!(parse_duration("20us"), Some(Duration::from_micros(20)));
+        assert_eq!(parse_duration("20µs"), Some(Duration::from_micros(20)));
+        assert_eq!(parse_duration("3"), None);
+        assert_eq!(parse_duration("3h"), None);
+    }
+}
@@ -0,0 +1,599 @@
+mod alloc;
+mod baseline;
+mod bench;
+mod output;
+mod readme;
+mod revision;
+mod scaffold;
+mod worker;
+
+use advent_of_code_2025::answers::{ANSWERS_FILE, Answers};
+use advent_of_code_2025::{DAYS, Day, InputError, InputSource, day};
+use alloc::AllocStats;
+use baseline::{Baseline, Verdict};
+use bench::{BenchConfig, Stats, measure, parse_duration};
+use output::Format;
+use std::env;
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const USAGE: &str = "Usage: runner scaffold DAY | runner [readme [--readme PATH]] [DAY|FIRST-LAST ...] [--part 1|2] [--jobs N] [--timeout DURATION] [--memory SIZE] [--format table|json|csv] [--answers PATH] [--record] [--bench] [--warmup N] [--samples N] [--time DURATION] [--save-baseline NAME] [--baseline NAME [--threshold PERCENT]] [--compare REV]";
+
+/// What the runner does with the results.
+#[derive(Default, PartialEq, Eq)]
+enum Command {
+    /// Print the results.
+    #[default]
+    Run,
+    /// Print the results, then rewrite the generated sections of the README.
+    Readme,
+    /// Generate the files of a new day instead of running anything.
+    Scaffold,
+}
+
+/// Command-line options of the runner.
+#[derive(Default)]
+struct Options {
+    command: Command,
+    /// Selected days, all registered days when empty.
+    days: Vec<u8>,
+    /// Only run this part.
+    part: Option<u8>,
+    /// README rewritten by the `readme` command.
+    readme: Option<PathBuf>,
+    format: Format,
+    /// Expected answers checked after the run.
+    answers: Option<PathBuf>,
+    /// Write the answers of this run instead of checking them.
+    record: bool,
+    /// Repeated measurements instead of a single run.
+    bench: Option<BenchConfig>,
+    /// Save the benchmark results under this baseline name.
+    save_baseline: Option<String>,
+    /// Compare the benchmark results with this baseline.
+    baseline: Option<String>,
+    /// Benchmark this git revision too and compare it with the current tree.
+    compare: Option<String>,
+    /// Relative slowdown (in percent) from which a significant change is a regression.
+    threshold: f64,
+    /// Number of days run concurrently (always 1 in benchmark mode).
+    jobs: usize,
+    /// Wall-clock limit of each day: `DEFAULT_TIMEOUT` unless `--timeout` is given,
+    /// none in benchmark mode whose duration depends on the sampling settings.
+    timeout: Option<Duration>,
+    /// Address-space limit of each worker, in bytes.
+    memory: Option<u64>,
+    /// Run the selected day in this process and report it to the parent runner.
+    worker: bool,
+}
+
+/// Parses the value following `arg`.
+fn parse_value<T: FromStr>(arg: &str, value: Option<String>) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("{} expects a value", arg))?;
+    value
+        .parse()
+        .map_err(|_| format!("invalid value {:?} for {}", value, arg))
+}
+
+/// Parses a day selection: a single day ("3") or an inclusive range ("5-8").
+fn parse_days(arg: &str) -> Result<Vec<u8>, String> {
+    let invalid = || format!("invalid day selection {:?}", arg);
+    let (first, last) = arg.split_once('-').unwrap_or((arg, arg));
+    let first: u8 = first.parse().map_err(|_| invalid())?;
+    let last: u8 = last.parse().map_err(|_| invalid())?;
+    if first > last {
+        return Err(invalid());
+    }
+    Ok((first..=last).collect())
+}
+
+impl Options {
+    fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut options = Options {
+            threshold: baseline::DEFAULT_THRESHOLD,
+            jobs: 1,
+            ..Options::default()
+        };
+        let mut timeout_set = false;
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "readme" => options.command = Command::Readme,
+                "scaffold" => options.command = Command::Scaffold,
+                "--readme" => options.readme = Some(parse_value(&arg, args.next())?),
+                "--format" => options.format = parse_value(&arg, args.next())?,
+                "--answers" => options.answers = Some(parse_value(&arg, args.next())?),
+                "--record" => options.record = true,
+                "--save-baseline" => {
+                    let name: String = parse_value(&arg, args.next())?;
+                    // Fail before benchmarking rather than when saving
+                    baseline::path(&name)?;
+                    options.save_baseline = Some(name);
+                    options.bench_config();
+                }
+                "--baseline" => {
+                    let name: String = parse_value(&arg, args.next())?;
+                    baseline::path(&name)?;
+                    options.baseline = Some(name);
+                    options.bench_config();
+                }
+                "--compare" => {
+                    options.compare = Some(parse_value(&arg, args.next())?);
+                    options.bench_config();
+                }
+                "--threshold" => options.threshold = parse_value(&arg, args.next())?,
+                "--jobs" | "-j" => {
+                    options.jobs = parse_value(&arg, args.next())?;
+                    if options.jobs == 0 {
+                        return Err("--jobs expects at least 1".to_string());
+                    }
+                }
+                "--timeout" => {
+                    let timeout: String = parse_value(&arg, args.next())?;
+                    options.timeout = match timeout.as_str() {
+                        "none" => None,
+                        _ => Some(
+                            parse_duration(&timeout)
+                                .ok_or_else(|| format!("invalid duration {:?}", timeout))?,
+                        ),
+                    };
+                    timeout_set = true;
+                }
+                "--memory" => {
+                    let memory: String = parse_value(&arg, args.next())?;
+                    options.memory = Some(
+                        worker::parse_size(&memory)
+                            .ok_or_else(|| format!("invalid memory size {:?}", memory))?,
+                    );
+                }
+                worker::WORKER_FLAG => options.worker = true,
+                "--part" => {
+                    let part = parse_value(&arg, args.next())?;
+                    if !(1..=2).contains(&part) {
+                        return Err(format!("invalid part {}, expected 1 or 2", part));
+                    }
+                    options.part = Some(part);
+                }
+                "--bench" => {
+                    options.bench_config();
+                }
+                "--warmup" => options.bench_config().warmup = parse_value(&arg, args.next())?,
+                "--samples" => options.bench_config().samples = parse_value(&arg, args.next())?,
+                "--time" => {
+                    let budget: String = parse_value(&arg, args.next())?;
+                    options.bench_config().budget = parse_duration(&budget)
+                        .ok_or_else(|| format!("invalid duration {:?}", budget))?;
+                }
+                _ if arg.starts_with(|c: char| c.is_ascii_digit()) => {
+                    options.days.extend(parse_days(&arg)?);
+                }
+                _ => return Err(format!("unexpected argument {:?}", arg)),
+            }
+        }
+        if options.command == Command::Readme {
+            if options.part.is_some() {
+                return Err("readme needs both parts, --part is not supported".to_string());
+            }
+            // The README only ever shows benchmark numbers
+            options.bench_config();
+        }
+        // Benchmarks take as long as their sampling settings ask for, so they are only
+        // limited on request
+        if !timeout_set && options.bench.is_none() {
+            options.timeout = Some(worker::DEFAULT_TIMEOUT);
+        }
+        if options.command == Command::Scaffold && options.days.len() != 1 {
+            return Err("scaffold expects a single day".to_string());
+        }
+        options.days.sort_unstable();
+        options.days.dedup();
+        Ok(options)
+    }
+
+    /// Whether `part` (1 or 2) should run.
+    fn runs_part(&self, part: u8) -> bool {
+        self.part.is_none_or(|selected| selected == part)
+    }
+
+    /// Registered days to run, in order; fails on days that are not registered.
+    fn selected_days(&self) -> Result<Vec<&'static Day>, String> {
+        if self.days.is_empty() {
+            return Ok(DAYS.iter().collect());
+        }
+        self.days
+            .iter()
+            .map(|&n| day(n).ok_or_else(|| format!("day {:02} is not implemented", n)))
+            .collect()
+    }
+
+    /// Benchmark settings, enabling benchmark mode if needed.
+    fn bench_config(&mut self) -> &mut BenchConfig {
+        self.bench.get_or_insert_with(BenchConfig::default)
+    }
+}
+
+/// Outcome of running one day.
+pub enum Status {
+    Success,
+    Missing,
+    Failed(String),
+    /// The day ran but its answers differ from the recorded ones.
+    Mismatch(String),
+    /// The day was killed after exceeding the timeout.
+    Timeout(String),
+    /// The day panicked; `backtrace` holds the frames in the solutions.
+    Panicked {
+        message: String,
+        backtrace: Vec<String>,
+    },
+}
+
+impl Status {
+    /// Whether the day ran to completion, so that its timings are meaningful.
+    pub fn ran(&self) -> bool {
+        matches!(self, Status::Success | Status::Mismatch(_))
+    }
+
+    /// Whether the day counts as a failure for the exit code.
+    ///
+    /// A day without input yet, such as one just scaffolded, is only reported.
+    pub fn failed(&self) -> bool {
+        !matches!(self, Status::Success | Status::Missing)
+    }
+
+    /// What went wrong, if anything.
+    pub fn message(&self) -> Option<&str> {
+        match self {
+            Status::Failed(message)
+            | Status::Mismatch(message)
+            | Status::Timeout(message)
+            | Status::Panicked { message, .. } => Some(message),
+            Status::Success | Status::Missing => None,
+        }
+    }
+}
+
+/// Answers and timings of one day, each step measured separately.
+pub struct DayResult {
+    pub day: u8,
+    pub title: &'static str,
+    pub status: Status,
+    pub answers: [Option<u64>; 2],
+    pub parse: Option<Stats>,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+    /// Heap usage of parsing, part 1 and part 2 (with the `alloc-stats` feature).
+    pub allocs: [Option<AllocStats>; 3],
+}
+
+impl DayResult {
+    /// Result of a day that has not run yet.
+    pub fn new(day: &Day) -> Self {
+        DayResult {
+            day: day.number,
+            title: day.title,
+            status: Status::Success,
+            answers: [None, None],
+            parse: None,
+            part1: None,
+            part2: None,
+            allocs: [None; 3],
+        }
+    }
+
+    /// Sum of the median time of each step.
+    pub fn total(&self) -> Duration {
+        [self.parse, self.part1, self.part2]
+            .iter()
+            .flatten()
+            .map(|stats| stats.median)
+            .sum()
+    }
+}
+
+/// Times a single call of `f`.
+fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let value = f();
+    (value, start.elapsed())
+}
+
+/// Reads, parses and solves a day in-process, then benchmarks each step if requested.
+fn run_day(day: &Day, options: &Options) -> DayResult {
+    let mut result = DayResult::new(day);
+
+    let raw = match InputSource::Day(day.number).read() {
+        Ok(raw) => raw,
+        Err(InputError::Missing { .. } | InputError::Empty { .. }) => {
+            result.status = Status::Missing;
+            return result;
+        }
+        Err(e) => {
+            result.status = Status::Failed(e.to_string());
+            return result;
+        }
+    };
+
+    let ((parsed, duration), allocs) = alloc::track(|| time(|| day.parse(&raw)));
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            result.status = Status::Failed(e.to_string());
+            return result;
+        }
+    };
+    result.parse = Some(Stats::single(duration));
+    result.allocs[0] = allocs;
+
+    if options.runs_part(1) {
+        let ((answer, duration), allocs) = alloc::track(|| time(|| parsed.part1()));
+        result.answers[0] = Some(answer);
+        result.part1 = Some(Stats::single(duration));
+        result.allocs[1] = allocs;
+    }
+
+    if options.runs_part(2) {
+        let ((answer, duration), allocs) = alloc::track(|| time(|| parsed.part2()));
+        result.answers[1] = answer;
+        result.part2 = answer.map(|_| Stats::single(duration));
+        result.allocs[2] = allocs.filter(|_| answer.is_some());
+    }
+
+    if let Some(config) = &options.bench {
+        result.parse = Some(measure(config, || day.parse(&raw)));
+        if result.part1.is_some() {
+            result.part1 = Some(measure(config, || parsed.part1()));
+        }
+        if result.part2.is_some() {
+            result.part2 = Some(measure(config, || parsed.part2()));
+        }
+    }
+
+    result
+}
+
+/// Runs the days on `options.jobs` threads, each day in its own worker, keeping day order.
+fn run_days(days: &[&'static Day], options: &Options) -> Vec<DayResult> {
+    // Concurrent days would disturb each other's timings
+    let jobs = if options.bench.is_some() {
+        1
+    } else {
+        options.jobs
+    };
+    if jobs <= 1 {
+        return days
+            .iter()
+            .map(|day| worker::run_isolated(day, options))
+            .collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let mut results: Vec<(usize, DayResult)> = thread::scope(|scope| {
+        let threads: Vec<_> = (0..jobs.min(days.len()))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut results = Vec::new();
+                    loop {
+                        let i = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(day) = days.get(i) else { break };
+                        results.push((i, worker::run_isolated(day, options)));
+                    }
+                    results
+                })
+            })
+            .collect();
+        threads
+            .into_iter()
+            .flat_map(|thread| thread.join().expect("runner thread panicked"))
+            .collect()
+    });
+    results.sort_by_key(|&(i, _)| i);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+fn main() -> ExitCode {
+    let options = match Options::parse(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{}", e);
+            eprintln!("{}", USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    if options.worker {
+        return worker::serve(&options);
+    }
+
+    if options.command == Command::Scaffold {
+        return match scaffold::generate(Path::new(""), options.days[0]) {
+            Ok(paths) => {
+                for path in paths {
+                    eprintln!("📝 {}", path.display());
+                }
+                eprintln!("✨ Jour {:02} prêt", options.days[0]);
+                ExitCode::SUCCESS
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    let days = match options.selected_days() {
+        Ok(days) => days,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::from(2);
+        }
+    };
+
+    let color = io::stdout().is_terminal();
+    if options.format == Format::Table {
+        print!("{}", output::banner(color, options.bench.as_ref()));
+    }
+
+    let answers_path = options
+        .answers
+        .clone()
+        .unwrap_or_else(|| PathBuf::from(ANSWERS_FILE));
+    let mut answers = match Answers::load(&answers_path) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let baseline = match options.baseline.as_deref().map(Baseline::load) {
+        Some(Ok(baseline)) => Some(baseline),
+        Some(Err(e)) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+        None => None,
+    };
+
+    let other = match options
+        .compare
+        .as_deref()
+        .map(|rev| revision::bench(rev, &options))
+    {
+        Some(Ok(other)) => Some(other),
+        Some(Err(e)) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+        None => None,
+    };
+
+    if options.jobs > 1 && options.bench.is_some() {
+        eprintln!("⚠️ --jobs est ignoré en mode benchmark, les jours sont exécutés un par un");
+    }
+    let mut results = run_days(&days, &options);
+
+    for result in results.iter_mut().filter(|r| r.status.ran()) {
+        if options.record {
+            answers.record(result.day, result.answers);
+            continue;
+        }
+        let mut mismatches = answers.check(result.day, result.answers);
+        mismatches.retain(|m| options.runs_part(m.part));
+        if !mismatches.is_empty() {
+            let messages: Vec<String> = mismatches.iter().map(|m| m.to_string()).collect();
+            result.status = Status::Mismatch(messages.join("; "));
+        }
+    }
+
+    let report = match options.format {
+        Format::Table => output::table(&results, options.bench.is_some(), color),
+        Format::Json => output::json(&results, options.bench.is_some()),
+        Format::Csv => output::csv(&results),
+    };
+    print!("{}", report);
+
+    let mut regressed = false;
+    if let (Some(name), Some(baseline)) = (&options.baseline, &baseline) {
+        let comparisons = baseline::compare(baseline, &results, options.threshold);
+        regressed = comparisons.iter().any(|c| c.verdict == Verdict::Regressed);
+        let report = output::comparison(name, &comparisons, options.threshold, color);
+        // Keep stdout machine-readable for the JSON and CSV formats
+        if options.format == Format::Table {
+            print!("{}", report);
+        } else {
+            eprint!("{}", report);
+        }
+    }
+
+    if let (Some(rev), Some(other)) = (&options.compare, &other) {
+        let report = output::speedup(rev, other, &results, color);
+        if options.format == Format::Table {
+            print!("{}", report);
+        } else {
+            eprint!("{}", report);
+        }
+    }
+
+    if let Some(name) = &options.save_baseline {
+        match Baseline::from_results(&results).save(name) {
+            Ok(path) => eprintln!("📝 {} mis à jour", path.display()),
+            Err(e) => {
+                eprintln!("{}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    if options.record {
+        if let Err(e) = answers.save(&answers_path) {
+            eprintln!("failed to write {}: {}", answers_path.display(), e);
+            return ExitCode::FAILURE;
+        }
+        eprintln!("📝 {} mis à jour", answers_path.display());
+    }
+
+    if options.command == Command::Readme {
+        let path = options.readme.unwrap_or_else(|| PathBuf::from("README.md"));
+        if let Err(e) = readme::update(&path, &results) {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+        eprintln!("📝 {} mis à jour", path.display());
+    }
+
+    if regressed || results.iter().any(|r| r.status.failed()) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(args: &[&str]) -> Result<Options, String> {
+        Options::parse(args.iter().map(|s| s.to_string()))
+    }
+
+    #[test]
+    fn test_day_selection() {
+        let selected = options(&["3", "5-8", "4", "5"]).unwrap();
+        assert_eq!(selected.days, [3, 4, 5, 6, 7, 8]);
+        assert!(options(&[]).unwrap().days.is_empty());
+        assert!(options(&["8-5"]).is_err());
+        assert!(options(&["3x"]).is_err());
+        assert!(options(&["300"]).is_err());
+        assert!(options(&["99"]).unwrap().selected_days().is_err());
+    }
+
+    #[test]
+    fn test_part_selection() {
+        let selected = options(&["--part", "2"]).unwrap();
+        assert!(!selected.runs_part(1) && selected.runs_part(2));
+        assert!(options(&["--part", "3"]).is_err());
+        assert!(options(&["readme", "--part", "1"]).is_err());
+        assert!(options(&["scaffold"]).is_err());
+        assert!(options(&["scaffold", "13-14"]).is_err());
+        assert_eq!(options(&["--jobs", "4"]).unwrap().jobs, 4);
+        assert!(options(&["--jobs", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_timeout() {
+        let timeout = |args: &[&str]| options(args).unwrap().timeout;
+        assert_eq!(timeout(&[]), Some(worker::DEFAULT_TIMEOUT));
+        assert_eq!(timeout(&["--timeout", "none"]), None);
+        assert_eq!(timeout(&["--bench", "--time", "30s"]), None);
+        assert_eq!(
+            timeout(&["--bench", "--timeout", "5s"]),
+            Some(Duration::from_secs(5))
+        );
+    }
+}
@@ -0,0 +1,724 @@
+//! Rendering of the runner results: terminal table, JSON and CSV.
+
+use crate::alloc::AllocStats;
+use crate::baseline::{Baseline, Comparison, Verdict};
+use crate::bench::{BenchConfig, Stats};
+use crate::{DayResult, Status};
+use std::fmt::Write;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Version of the JSON and CSV schemas, bumped on any incompatible change.
+pub const SCHEMA_VERSION: u32 = 2;
+
+/// Output format selected with `--format`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Table,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, ()> {
+        match s {
+            "table" => Ok(Format::Table),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Wraps `text` in an ANSI escape sequence when colors are enabled.
+fn paint(color: bool, code: &str, text: &str) -> String {
+    if color {
+        format!("\x1b[{}m{}\x1b[0m", code, text)
+    } else {
+        text.to_string()
+    }
+}
+
+/// Christmas tree and title printed before the table.
+pub fn banner(color: bool, bench: Option<&BenchConfig>) -> String {
+    // ASCII Art de Noël
+    let tree = r#"
+        .   *   .       .   .   *      .   .
+      .   *  .  .   *   .   .      *   .
+         .   /\   .   *    .    *   .   .
+       *    /  \      .   .    *   .
+           /    \   *   .     .   *
+          /      \     .   *       .
+         /  ~  ~  \   .       .   .
+        /  ~  ~  ~ \      *   .
+       /____________\  .     .
+            |  |   .   *   .   .
+         ___|__|___    .   .
+    "#;
+
+    let mut out = String::new();
+    writeln!(out, "\n{}\n", paint(color, "32", tree)).unwrap();
+    writeln!(
+        out,
+        "{}\n",
+        paint(
+            color,
+            "1;31",
+            "🎄 Advent of Code 2025 - Runner & Benchmark 🎄"
+        )
+    )
+    .unwrap();
+    if let Some(config) = bench {
+        let text = format!(
+            "Benchmark : {} itérations de chauffe, jusqu'à {} échantillons ou {:?} par étape",
+            config.warmup, config.samples, config.budget
+        );
+        writeln!(out, "📊 {}\n", paint(color, "1", &text)).unwrap();
+    }
+    out
+}
+
+/// Horizontal rule of a table with the given column widths.
+fn rule(out: &mut String, left: &str, mid: &str, right: &str, widths: &[usize]) {
+    let cells: Vec<String> = widths.iter().map(|&w| "─".repeat(w + 2)).collect();
+    writeln!(out, "{}{}{}", left, cells.join(mid), right).unwrap();
+}
+
+/// Row of a table, padding each cell to its column width.
+fn row(out: &mut String, cells: &[&str], widths: &[usize]) {
+    let cells: Vec<String> = cells
+        .iter()
+        .zip(widths)
+        .map(|(cell, &w)| format!(" {:<w$} ", cell, w = w))
+        .collect();
+    writeln!(out, "│{}│", cells.join("│")).unwrap();
+}
+
+/// `text` cut to `width` characters, ending with "…" when shortened.
+fn truncate(text: &str, width: usize) -> String {
+    if text.chars().count() <= width {
+        return text.to_string();
+    }
+    let mut cut: String = text.chars().take(width - 1).collect();
+    cut.push('…');
+    cut
+}
+
+/// Status cell, accounting for the display width of its emoji.
+fn status_cell(color: bool, status: &Status) -> String {
+    match status {
+        Status::Success => format!("{}│", paint(color, "32", &format!("{:<13}", "✅ Succès"))),
+        Status::Missing => format!(
+            "{} │",
+            paint(color, "33", &format!("{:<13}", "⚠️ Manquant"))
+        ),
+        Status::Failed(_) => format!("{}│", paint(color, "31", &format!("{:<13}", "❌ Échec"))),
+        Status::Mismatch(_) => format!(
+            "{}│",
+            paint(color, "31", &format!("{:<13}", "❌ Incorrect"))
+        ),
+        Status::Timeout(_) => format!("{}│", paint(color, "31", &format!("{:<13}", "⌛ Expiré"))),
+        Status::Panicked { .. } => {
+            format!("{}│", paint(color, "31", &format!("{:<13}", "💥 Panique")))
+        }
+    }
+}
+
+fn format_median(stats: Option<Stats>) -> String {
+    match stats {
+        Some(stats) => format!("{:.2?}", stats.median),
+        None => "-".to_string(),
+    }
+}
+
+/// Formats a number of bytes with a binary unit ("512 B", "1.5 KiB", "3.2 MiB").
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+/// Heap usage of each step, one line per step.
+fn memory_table(out: &mut String, results: &[DayResult]) {
+    const WIDTHS: [usize; 5] = [8, 9, 11, 11, 11];
+
+    writeln!(out).unwrap();
+    rule(out, "┌", "┬", "┐", &WIDTHS);
+    row(
+        out,
+        &["Jour", "Étape", "Pic", "Alloué", "Allocations"],
+        &WIDTHS,
+    );
+    for result in results {
+        let steps = ["Parsing", "Partie 1", "Partie 2"];
+        let mut first = true;
+        for (step, allocs) in steps.iter().zip(result.allocs) {
+            let Some(a) = allocs else { continue };
+            if first {
+                rule(out, "├", "┼", "┤", &WIDTHS);
+            }
+            let day_str = if first {
+                format!("day{:02}", result.day)
+            } else {
+                String::new()
+            };
+            first = false;
+            row(
+                out,
+                &[
+                    &day_str,
+                    step,
+                    &format_bytes(a.peak),
+                    &format_bytes(a.bytes),
+                    &a.count.to_string(),
+                ],
+                &WIDTHS,
+            );
+        }
+    }
+    rule(out, "└", "┴", "┘", &WIDTHS);
+}
+
+/// Table for the terminal, followed by errors and the totals.
+pub fn table(results: &[DayResult], bench: bool, color: bool) -> String {
+    let mut out = String::new();
+    if bench {
+        bench_table(&mut out, results);
+    } else {
+        single_table(&mut out, results, color);
+    }
+    if results.iter().any(|r| r.allocs.iter().any(Option::is_some)) {
+        memory_table(&mut out, results);
+    }
+
+    for result in results {
+        if let Some(message) = result.status.message() {
+            let day_str = format!("day{:02}:", result.day);
+            writeln!(out, "{} {}", paint(color, "31", &day_str), message).unwrap();
+        }
+        if let Status::Panicked { backtrace, .. } = &result.status {
+            for frame in backtrace {
+                writeln!(out, "    {}", paint(color, "2", frame)).unwrap();
+            }
+        }
+    }
+
+    let successes: Vec<&DayResult> = results.iter().filter(|r| r.status.ran()).collect();
+    let total_duration: std::time::Duration = successes.iter().map(|r| r.total()).sum();
+    writeln!(
+        out,
+        "\n{} {:.2?}",
+        paint(color, "1", "Temps Total:"),
+        total_duration
+    )
+    .unwrap();
+    if !successes.is_empty() {
+        let avg = total_duration / successes.len() as u32;
+        writeln!(
+            out,
+            "{} {:.2?}",
+            paint(color, "1", "Moyenne par jour:"),
+            avg
+        )
+        .unwrap();
+    }
+    writeln!(
+        out,
+        "\n{}",
+        paint(color, "1;31", "🎅 Joyeux Noël et bon code ! 🎅")
+    )
+    .unwrap();
+    out
+}
+
+/// One line per day with the time of each step.
+fn single_table(out: &mut String, results: &[DayResult], color: bool) {
+    const WIDTHS: [usize; 6] = [8, 13, 10, 10, 10, 10];
+
+    rule(out, "┌", "┬", "┐", &WIDTHS);
+    row(
+        out,
+        &["Jour", "Statut", "Parsing", "Partie 1", "Partie 2", "Total"],
+        &WIDTHS,
+    );
+    rule(out, "├", "┼", "┤", &WIDTHS);
+
+    for result in results {
+        let total = if result.status.ran() {
+            format!("{:.2?}", result.total())
+        } else {
+            "-".to_string()
+        };
+        writeln!(
+            out,
+            "│ {:<8} │ {} {:<10} │ {:<10} │ {:<10} │ {:<10} │",
+            format!("day{:02}", result.day),
+            status_cell(color, &result.status),
+            format_median(result.parse),
+            format_median(result.part1),
+            format_median(result.part2),
+            total
+        )
+        .unwrap();
+    }
+
+    rule(out, "└", "┴", "┘", &WIDTHS);
+}
+
+/// One line per step of each day with its statistics.
+fn bench_table(out: &mut String, results: &[DayResult]) {
+    const WIDTHS: [usize; 8] = [8, 9, 10, 10, 10, 10, 6, 9];
+
+    rule(out, "┌", "┬", "┐", &WIDTHS);
+    row(
+        out,
+        &[
+            "Jour",
+            "Étape",
+            "Min",
+            "Médiane",
+            "Moyenne",
+            "Écart-type",
+            "N",
+            "Aberrants",
+        ],
+        &WIDTHS,
+    );
+
+    for result in results {
+        rule(out, "├", "┼", "┤", &WIDTHS);
+        let day_str = format!("day{:02}", result.day);
+        let status = match result.status {
+            Status::Success | Status::Mismatch(_) => None,
+            Status::Missing => Some("Manquant"),
+            Status::Failed(_) => Some("Échec"),
+            Status::Timeout(_) => Some("Expiré"),
+            Status::Panicked { .. } => Some("Panique"),
+        };
+        if let Some(status) = status {
+            row(
+                out,
+                &[&day_str, status, "-", "-", "-", "-", "-", "-"],
+                &WIDTHS,
+            );
+            continue;
+        }
+
+        let steps = [
+            ("Parsing", result.parse),
+            ("Partie 1", result.part1),
+            ("Partie 2", result.part2),
+        ];
+        for (i, (step, stats)) in steps.iter().enumerate() {
+            let Some(stats) = stats else { continue };
+            row(
+                out,
+                &[
+                    if i == 0 { &day_str } else { "" },
+                    step,
+                    &format!("{:.2?}", stats.min),
+                    &format!("{:.2?}", stats.median),
+                    &format!("{:.2?}", stats.mean),
+                    &format!("{:.2?}", stats.stddev),
+                    &stats.samples.to_string(),
+                    &stats.outliers.to_string(),
+                ],
+                &WIDTHS,
+            );
+        }
+    }
+
+    rule(out, "└", "┴", "┘", &WIDTHS);
+}
+
+/// Comparison of each step with a baseline, one line per step.
+pub fn comparison(name: &str, comparisons: &[Comparison], threshold: f64, color: bool) -> String {
+    const WIDTHS: [usize; 7] = [8, 9, 10, 10, 9, 7, 14];
+
+    let mut out = String::new();
+    let title = format!(
+        "Comparaison avec la référence « {} » (seuil {}%, test de Welch)",
+        name, threshold
+    );
+    writeln!(out, "\n📈 {}\n", paint(color, "1", &title)).unwrap();
+    if comparisons.is_empty() {
+        writeln!(out, "Aucune étape en commun avec la référence.").unwrap();
+        return out;
+    }
+
+    rule(&mut out, "┌", "┬", "┐", &WIDTHS);
+    row(
+        &mut out,
+        &[
+            "Jour",
+            "Étape",
+            "Référence",
+            "Actuel",
+            "Écart",
+            "p",
+            "Verdict",
+        ],
+        &WIDTHS,
+    );
+    rule(&mut out, "├", "┼", "┤", &WIDTHS);
+    for c in comparisons {
+        let verdict = match c.verdict {
+            Verdict::Improved => paint(color, "32", &format!("{:<13}", "🟢 Amélioré")),
+            Verdict::Unchanged => format!("{:<13}", "⚪ Stable"),
+            Verdict::Regressed => paint(color, "31", &format!("{:<13}", "🔴 Régression")),
+        };
+        writeln!(
+            out,
+            "│ {:<8} │ {:<9} │ {:<10} │ {:<10} │ {:<9} │ {:<7} │ {} │",
+            format!("day{:02}", c.day),
+            c.step,
+            format!("{:.2?}", c.baseline.mean),
+            format!("{:.2?}", c.current.mean),
+            format!("{:+.1}%", c.change),
+            format!("{:.3}", c.p_value),
+            verdict
+        )
+        .unwrap();
+    }
+    rule(&mut out, "└", "┴", "┘", &WIDTHS);
+    out
+}
+
+/// Per-day speedup of the current tree over another revision (sum of the step medians).
+pub fn speedup(revision: &str, other: &Baseline, results: &[DayResult], color: bool) -> String {
+    const WIDTHS: [usize; 4] = [8, 12, 12, 12];
+
+    let mut out = String::new();
+    let title = format!("Accélération par rapport à {}", revision);
+    writeln!(out, "\n🚀 {}\n", paint(color, "1", &title)).unwrap();
+
+    let mut totals = [Duration::ZERO; 2];
+    rule(&mut out, "┌", "┬", "┐", &WIDTHS);
+    // The title holds the full name, the header only what fits its column
+    row(
+        &mut out,
+        &[
+            "Jour",
+            &truncate(revision, WIDTHS[1]),
+            "Actuel",
+            "Accélération",
+        ],
+        &WIDTHS,
+    );
+    rule(&mut out, "├", "┼", "┤", &WIDTHS);
+    for result in results.iter().filter(|r| r.status.ran()) {
+        let Some(before) = other.day_total(result.day) else {
+            continue;
+        };
+        let after = result.total();
+        totals[0] += before;
+        totals[1] += after;
+        speedup_row(
+            &mut out,
+            &format!("day{:02}", result.day),
+            before,
+            after,
+            color,
+        );
+    }
+    rule(&mut out, "├", "┼", "┤", &WIDTHS);
+    speedup_row(&mut out, "Total", totals[0], totals[1], color);
+    rule(&mut out, "└", "┴", "┘", &WIDTHS);
+    out
+}
+
+fn speedup_row(out: &mut String, label: &str, before: Duration, after: Duration, color: bool) {
+    let ratio = before.as_secs_f64() / after.as_secs_f64().max(f64::MIN_POSITIVE);
+    let cell = format!("{:<12}", format!("×{:.2}", ratio));
+    let cell = match ratio {
+        r if r >= 1.05 => paint(color, "32", &cell),
+        r if r <= 0.95 => paint(color, "31", &cell),
+        _ => cell,
+    };
+    writeln!(
+        out,
+        "│ {:<8} │ {:<12} │ {:<12} │ {} │",
+        label,
+        format!("{:.2?}", before),
+        format!("{:.2?}", after),
+        cell
+    )
+    .unwrap();
+}
+
+/// Machine-readable status name.
+fn status_name(status: &Status) -> &'static str {
+    match status {
+        Status::Success => "success",
+        Status::Missing => "missing",
+        Status::Failed(_) => "failed",
+        Status::Mismatch(_) => "mismatch",
+        Status::Timeout(_) => "timeout",
+        Status::Panicked { .. } => "panic",
+    }
+}
+
+/// Quotes and escapes a JSON string.
+fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn json_option<T: ToString>(value: Option<T>) -> String {
+    value.map_or_else(|| "null".to_string(), |v| v.to_string())
+}
+
+fn json_stats(stats: Option<Stats>) -> String {
+    match stats {
+        Some(s) => format!(
+            r#"{{"samples": {}, "min_ns": {}, "median_ns": {}, "mean_ns": {}, "stddev_ns": {}, "outliers": {}}}"#,
+            s.samples,
+            s.min.as_nanos(),
+            s.median.as_nanos(),
+            s.mean.as_nanos(),
+            s.stddev.as_nanos(),
+            s.outliers
+        ),
+        None => "null".to_string(),
+    }
+}
+
+fn json_allocs(allocs: Option<AllocStats>) -> String {
+    match allocs {
+        Some(a) => format!(
+            r#"{{"peak_bytes": {}, "allocated_bytes": {}, "allocations": {}}}"#,
+            a.peak, a.bytes, a.count
+        ),
+        None => "null".to_string(),
+    }
+}
+
+/// JSON document with one object per day; durations are in nanoseconds.
+pub fn json(results: &[DayResult], bench: bool) -> String {
+    let mut out = String::new();
+    writeln!(out, "{{").unwrap();
+    writeln!(out, "  \"schema_version\": {},", SCHEMA_VERSION).unwrap();
+    writeln!(
+        out,
+        "  \"mode\": {},",
+        json_string(if bench { "bench" } else { "single" })
+    )
+    .unwrap();
+    writeln!(out, "  \"days\": [").unwrap();
+    for (i, result) in results.iter().enumerate() {
+        let error = result.status.message().map(json_string);
+        writeln!(out, "    {{").unwrap();
+        writeln!(out, "      \"day\": {},", result.day).unwrap();
+        writeln!(out, "      \"title\": {},", json_string(result.title)).unwrap();
+        writeln!(
+            out,
+            "      \"status\": {},",
+            json_string(status_name(&result.status))
+        )
+        .unwrap();
+        writeln!(out, "      \"error\": {},", json_option(error)).unwrap();
+        if let Status::Panicked { backtrace, .. } = &result.status {
+            let frames: Vec<String> = backtrace.iter().map(|f| json_string(f)).collect();
+            writeln!(out, "      \"backtrace\": [{}],", frames.join(", ")).unwrap();
+        }
+        writeln!(
+            out,
+            "      \"answers\": {{\"part1\": {}, \"part2\": {}}},",
+            json_option(result.answers[0]),
+            json_option(result.answers[1])
+        )
+        .unwrap();
+        writeln!(out, "      \"timings\": {{").unwrap();
+        writeln!(out, "        \"parse\": {},", json_stats(result.parse)).unwrap();
+        writeln!(out, "        \"part1\": {},", json_stats(result.part1)).unwrap();
+        writeln!(out, "        \"part2\": {}", json_stats(result.part2)).unwrap();
+        writeln!(out, "      }},").unwrap();
+        writeln!(out, "      \"memory\": {{").unwrap();
+        writeln!(out, "        \"parse\": {},", json_allocs(result.allocs[0])).unwrap();
+        writeln!(out, "        \"part1\": {},", json_allocs(result.allocs[1])).unwrap();
+        writeln!(out, "        \"part2\": {}", json_allocs(result.allocs[2])).unwrap();
+        writeln!(out, "      }}").unwrap();
+        let comma = if i + 1 < results.len() { "," } else { "" };
+        writeln!(out, "    }}{}", comma).unwrap();
+    }
+    writeln!(out, "  ]").unwrap();
+    writeln!(out, "}}").unwrap();
+    out
+}
+
+/// Quotes a CSV field when needed.
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// CSV with one row per step of each day (a single row for days that did not run).
+pub fn csv(results: &[DayResult]) -> String {
+    let mut out = String::from(
+        "day,title,status,step,answer,samples,min_ns,median_ns,mean_ns,stddev_ns,outliers,peak_bytes,allocated_bytes,allocations,error\n",
+    );
+    for result in results {
+        let prefix = format!(
+            "{},{},{}",
+            result.day,
+            csv_field(result.title),
+            status_name(&result.status)
+        );
+        let error = result.status.message().map(csv_field).unwrap_or_default();
+        if !result.status.ran() {
+            writeln!(out, "{},,,,,,,,,,,,{}", prefix, error).unwrap();
+            continue;
+        }
+
+        let steps = [
+            ("parse", None, result.parse, result.allocs[0]),
+            ("part1", result.answers[0], result.part1, result.allocs[1]),
+            ("part2", result.answers[1], result.part2, result.allocs[2]),
+        ];
+        for (step, answer, stats, allocs) in steps {
+            let Some(s) = stats else { continue };
+            let allocs = match allocs {
+                Some(a) => format!("{},{},{}", a.peak, a.bytes, a.count),
+                None => ",,".to_string(),
+            };
+            writeln!(
+                out,
+                "{},{},{},{},{},{},{},{},{},{},{}",
+                prefix,
+                step,
+                answer.map(|a| a.to_string()).unwrap_or_default(),
+                s.samples,
+                s.min.as_nanos(),
+                s.median.as_nanos(),
+                s.mean.as_nanos(),
+                s.stddev.as_nanos(),
+                s.outliers,
+                allocs,
+                error
+            )
+            .unwrap();
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn results() -> Vec<DayResult> {
+        let stats = Some(Stats::single(Duration::from_micros(5)));
+        vec![
+            DayResult {
+                day: 1,
+                title: "Secret Entrance",
+                status: Status::Success,
+                answers: [Some(3), Some(6)],
+                parse: stats,
+                part1: stats,
+                part2: stats,
+                allocs: [
+                    Some(AllocStats {
+                        peak: 2048,
+                        bytes: 4096,
+                        count: 3,
+                    }),
+                    None,
+                    None,
+                ],
+            },
+            DayResult {
+                day: 2,
+                title: "Gift Shop",
+                status: Status::Failed("line 1, column 4: invalid number \"x\"".to_string()),
+                answers: [None, None],
+                parse: None,
+                part1: None,
+                part2: None,
+                allocs: [None; 3],
+            },
+        ]
+    }
+
+    #[test]
+    fn test_csv() {
+        let csv = csv(&results());
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 5);
+        assert_eq!(
+            lines[1],
+            "1,Secret Entrance,success,parse,,1,5000,5000,5000,0,0,2048,4096,3,"
+        );
+        assert_eq!(
+            lines[2],
+            "1,Secret Entrance,success,part1,3,1,5000,5000,5000,0,0,,,,"
+        );
+        assert_eq!(
+            lines[4],
+            "2,Gift Shop,failed,,,,,,,,,,,,\"line 1, column 4: invalid number \"\"x\"\"\""
+        );
+    }
+
+    #[test]
+    fn test_json_escapes_errors() {
+        let json = json(&results(), false);
+        assert!(json.contains(r#""answers": {"part1": 3, "part2": 6}"#));
+        assert!(json.contains(r#""error": "line 1, column 4: invalid number \"x\"""#));
+        assert!(!json.contains('\x1b'));
+        assert!(json.contains(
+            r#""parse": {"peak_bytes": 2048, "allocated_bytes": 4096, "allocations": 3}"#
+        ));
+    }
+
+    #[test]
+    fn test_speedup_long_revision() {
+        let table = speedup(
+            "feature/faster-day-10",
+            &Baseline::default(),
+            &results(),
+            false,
+        );
+        assert!(table.contains("│ feature/fas… │"));
+        let widths: Vec<usize> = table
+            .lines()
+            .filter(|line| line.starts_with(['┌', '│', '├', '└']))
+            .map(|line| line.chars().count())
+            .collect();
+        assert!(widths.windows(2).all(|w| w[0] == w[1]), "{}", table);
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 << 20), "3.0 MiB");
+    }
+}
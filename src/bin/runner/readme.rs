@@ -0,0 +1,121 @@
+//! Regeneration of the README sections that come from runner results.
+//!
+//! Each generated section sits between `<!-- name:start -->` and `<!-- name:end -->`
+//! markers; everything outside the markers is left untouched.
+
+use crate::{DayResult, Status};
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+/// Formats a duration the way the README does ("51µs", "138ms", "1.20s").
+fn short_duration(duration: Duration) -> String {
+    let micros = duration.as_micros();
+    if micros < 1_000 {
+        format!("{}µs", micros)
+    } else if micros < 1_000_000 {
+        format!("{}ms", micros / 1_000)
+    } else {
+        format!("{:.2}s", duration.as_secs_f64())
+    }
+}
+
+fn short_stats(stats: Option<crate::bench::Stats>) -> String {
+    stats.map_or_else(|| "-".to_string(), |s| short_duration(s.median))
+}
+
+/// Checklist with one line per day, checked when the day ran successfully.
+pub fn progress(results: &[DayResult]) -> String {
+    let mut out = String::new();
+    for result in results {
+        if matches!(result.status, Status::Success) {
+            writeln!(out, "- [x] Day {:02} ⭐⭐", result.day).unwrap();
+        } else {
+            writeln!(out, "- [ ] Day {:02}", result.day).unwrap();
+        }
+    }
+    out
+}
+
+/// Benchmark table (median of each step) followed by the grand total.
+pub fn benchmarks(results: &[DayResult]) -> String {
+    let mut out = String::new();
+    writeln!(out, "| Day | Parse | Part 1 | Part 2 | Total |").unwrap();
+    writeln!(out, "|-----|-------|--------|--------|-------|").unwrap();
+
+    let mut grand_total = Duration::ZERO;
+    for result in results {
+        if !result.status.ran() {
+            writeln!(out, "| {:02} | - | - | - | - |", result.day).unwrap();
+            continue;
+        }
+        grand_total += result.total();
+        writeln!(
+            out,
+            "| {:02} | {} | {} | {} | **{}** |",
+            result.day,
+            short_stats(result.parse),
+            short_stats(result.part1),
+            short_stats(result.part2),
+            short_duration(result.total())
+        )
+        .unwrap();
+    }
+
+    writeln!(out, "\n=> **Total: {}**", short_duration(grand_total)).unwrap();
+    out
+}
+
+/// Replaces the content between the markers of section `name`.
+pub fn replace_section(document: &str, name: &str, content: &str) -> Result<String, String> {
+    let start_marker = format!("<!-- {}:start -->", name);
+    let end_marker = format!("<!-- {}:end -->", name);
+
+    let start = document
+        .find(&start_marker)
+        .ok_or_else(|| format!("marker {} not found", start_marker))?
+        + start_marker.len();
+    let end = document[start..]
+        .find(&end_marker)
+        .ok_or_else(|| format!("marker {} not found", end_marker))?
+        + start;
+
+    Ok(format!(
+        "{}\n\n{}\n\n{}",
+        &document[..start],
+        content.trim_end(),
+        &document[end..]
+    ))
+}
+
+/// Rewrites the progress and benchmark sections of the README at `path`.
+pub fn update(path: &Path, results: &[DayResult]) -> Result<(), String> {
+    let document = fs::read_to_string(path)
+        .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+    let document = replace_section(&document, "progress", &progress(results))?;
+    let document = replace_section(&document, "benchmarks", &benchmarks(results))?;
+    fs::write(path, document).map_err(|e| format!("failed to write {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_duration() {
+        assert_eq!(short_duration(Duration::from_nanos(51_400)), "51µs");
+        assert_eq!(short_duration(Duration::from_micros(138_900)), "138ms");
+        assert_eq!(short_duration(Duration::from_millis(1_204)), "1.20s");
+    }
+
+    #[test]
+    fn test_replace_section() {
+        let document = "# Title\n<!-- table:start -->\nold\n<!-- table:end -->\nFooter\n";
+        assert_eq!(
+            replace_section(document, "table", "new\n").unwrap(),
+            "# Title\n<!-- table:start -->\n\nnew\n\n<!-- table:end -->\nFooter\n"
+        );
+        assert!(replace_section(document, "other", "new").is_err());
+    }
+}
@@ -0,0 +1,147 @@
+//! Benchmarking another git revision of the repository.
+//!
+//! The revision is checked out in a temporary worktree, its runner is built there and
+//! run with the same benchmark options, and its CSV output is read back as a baseline.
+//! The build directory is shared between runs so that comparing the same revision
+//! again is cheap.
+
+use crate::Options;
+use crate::baseline::Baseline;
+use advent_of_code_2025::input::{INPUT_DIR_VAR, input_dir};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Stdio};
+
+/// Directory holding the temporary worktrees.
+const WORKTREE_DIR: &str = "target/worktrees";
+
+/// Build directory of the other revisions.
+const TARGET_DIR: &str = "target/revisions";
+
+/// Runs `command`, returning its stdout or an error with its stderr.
+fn output(command: &mut Command) -> Result<String, String> {
+    let output = command
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|e| format!("failed to run {:?}: {}", command.get_program(), e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "{:?} failed ({}):\n{}",
+            command.get_program(),
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim_end()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// A detached git worktree, removed when dropped.
+struct Worktree {
+    path: PathBuf,
+}
+
+impl Worktree {
+    fn add(revision: &str) -> Result<Self, String> {
+        let path = env::current_dir()
+            .map_err(|e| format!("failed to locate the repository: {}", e))?
+            .join(WORKTREE_DIR)
+            .join(process::id().to_string());
+        output(
+            Command::new("git")
+                .args(["worktree", "add", "--detach", "--quiet"])
+                .arg(&path)
+                .arg(revision),
+        )?;
+        Ok(Worktree { path })
+    }
+}
+
+impl Drop for Worktree {
+    fn drop(&mut self) {
+        let _ = output(
+            Command::new("git")
+                .args(["worktree", "remove", "--force"])
+                .arg(&self.path),
+        );
+    }
+}
+
+/// Whether the runner in the worktree at `root` has the `--format csv` output that
+/// `bench` reads back; older revisions only print tables.
+fn supports_csv(root: &Path) -> bool {
+    let sources = fs::read_dir(root.join("src/bin/runner"))
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .chain([root.join("src/bin/runner.rs")]);
+    sources
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .any(|source| source.contains("\"csv\""))
+}
+
+/// Arguments running the same benchmark protocol as `options`, with CSV output.
+fn runner_args(options: &Options) -> Vec<String> {
+    let mut args: Vec<String> = options.days.iter().map(u8::to_string).collect();
+    if let Some(part) = options.part {
+        args.extend(["--part".to_string(), part.to_string()]);
+    }
+    if let Some(config) = &options.bench {
+        args.extend([
+            "--warmup".to_string(),
+            config.warmup.to_string(),
+            "--samples".to_string(),
+            config.samples.to_string(),
+            "--time".to_string(),
+            format!("{}us", config.budget.as_micros()),
+        ]);
+    }
+    args.extend(["--format".to_string(), "csv".to_string()]);
+    args
+}
+
+/// Builds and benchmarks `revision`, on the same inputs as the current tree.
+pub fn bench(revision: &str, options: &Options) -> Result<Baseline, String> {
+    let root = env::current_dir().map_err(|e| format!("failed to locate the repository: {}", e))?;
+    let commit = output(
+        Command::new("git")
+            .args(["rev-parse", "--short", "--verify"])
+            .arg(format!("{}^{{commit}}", revision)),
+    )?;
+    eprintln!("🔧 Construction de {} ({})…", revision, commit.trim());
+
+    let worktree = Worktree::add(revision)?;
+    if !supports_csv(&worktree.path) {
+        return Err(format!(
+            "the runner of {} has no --format csv output, so it cannot be compared; \
+             pick a revision that has it",
+            revision
+        ));
+    }
+    let target_dir = root.join(TARGET_DIR);
+    output(
+        Command::new("cargo")
+            .args(["build", "--release", "--quiet", "--bin", "runner"])
+            .current_dir(&worktree.path)
+            .env("CARGO_TARGET_DIR", &target_dir),
+    )?;
+
+    eprintln!("⏱️ Benchmark de {}…", revision);
+    // The runner of the revision may report failures: only its timings matter here
+    let csv = Command::new(target_dir.join("release/runner"))
+        .args(runner_args(options))
+        .current_dir(&worktree.path)
+        .env(INPUT_DIR_VAR, root.join(input_dir()))
+        .stdin(Stdio::null())
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|e| format!("failed to run the runner of {}: {}", revision, e))?;
+    if csv.stdout.is_empty() {
+        return Err(format!(
+            "the runner of {} failed ({})",
+            revision, csv.status
+        ));
+    }
+    Baseline::from_csv(&String::from_utf8_lossy(&csv.stdout))
+        .map_err(|e| format!("unexpected output from the runner of {}: {}", revision, e))
+}
@@ -0,0 +1,241 @@
+//! Generation of the files of a new day.
+//!
+//! `runner scaffold <day>` creates the solution module with empty parts, its binary,
+//! an empty input and an empty example fixture, then registers the day in
+//! `src/days/mod.rs`, in `DAYS`, in the example answers and in the README progress
+//! list. Existing files are never overwritten.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Solution module; `{XX}` is the zero-padded day and `{N}` the day number.
+const SOLUTION: &str = r#"use crate::{InputError, Solution, lines};
+
+/// Parse the input, one entry per line
+fn parse_input(input: &str) -> Result<Vec<&str>, InputError> {
+    Ok(lines(input).collect())
+}
+
+fn part1(_input: &[&str]) -> u64 {
+    0
+}
+
+fn part2(_input: &[&str]) -> u64 {
+    0
+}
+
+pub struct Day{XX};
+
+impl Solution for Day{XX} {
+    const DAY: u8 = {N};
+    const TITLE: &'static str = "Day {XX}";
+    const SUMMARY: &'static [&'static str] = &["À compléter", "À compléter"];
+
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, InputError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Option<u64> {
+        Some(part2(input))
+    }
+}
+"#;
+
+/// Binary running the day on its own.
+const BINARY: &str = r#"use advent_of_code_2025::days::day{XX}::Day{XX};
+use advent_of_code_2025::run;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    run::<Day{XX}>()
+}
+"#;
+
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("{XX}", &format!("{:02}", day))
+        .replace("{N}", &day.to_string())
+}
+
+/// Day number mentioned in a line ("pub mod day07;", "- [ ] Day 07"), if any.
+fn day_number(line: &str) -> Option<u8> {
+    let lower = line.to_ascii_lowercase();
+    lower.match_indices("day").find_map(|(at, _)| {
+        let rest = lower[at + 3..].trim_start();
+        rest.get(..2)?.parse().ok()
+    })
+}
+
+/// Inserts `new_line` among the day lines between `start` and `end` (or the end of
+/// the document), keeping day order.
+fn insert_line(
+    document: &str,
+    start: &str,
+    end: Option<&str>,
+    new_line: &str,
+    day: u8,
+) -> Result<String, String> {
+    let region_start = document
+        .find(start)
+        .ok_or_else(|| format!("{:?} not found", start))?
+        + start.len();
+    let region_end = match end {
+        Some(end) => {
+            document[region_start..]
+                .find(end)
+                .ok_or_else(|| format!("{:?} not found", end))?
+                + region_start
+        }
+        None => document.len(),
+    };
+
+    // Offset of the first day line after `day`, or just past the last day line
+    let mut at = None;
+    let mut offset = region_start;
+    for line in document[region_start..region_end].split_inclusive('\n') {
+        if let Some(number) = day_number(line) {
+            if number == day {
+                return Err(format!("day {:02} is already listed", day));
+            }
+            if number > day {
+                at = Some(offset);
+                break;
+            }
+            at = Some(offset + line.len());
+        }
+        offset += line.len();
+    }
+    let at = at.ok_or_else(|| format!("no day listed after {:?}", start))?;
+
+    let indent: String = document[..at]
+        .rsplit('\n')
+        .nth(1)
+        .map(|previous| previous.chars().take_while(|c| *c == ' ').collect())
+        .unwrap_or_default();
+    Ok(format!(
+        "{}{}{}\n{}",
+        &document[..at],
+        indent,
+        new_line,
+        &document[at..]
+    ))
+}
+
+/// Creates and registers the files of `day` under `root`, returning the touched paths.
+pub fn generate(root: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("invalid day {}, expected 1 to 25", day));
+    }
+
+    let new_files = [
+        (
+            root.join(format!("src/days/day{:02}.rs", day)),
+            render(SOLUTION, day),
+        ),
+        (
+            root.join(format!("src/bin/day{:02}.rs", day)),
+            render(BINARY, day),
+        ),
+        (
+            root.join(format!("inputs/day{:02}.txt", day)),
+            String::new(),
+        ),
+        (
+            root.join(format!("inputs/examples/day{:02}-1.txt", day)),
+            String::new(),
+        ),
+    ];
+    if let Some((path, _)) = new_files.iter().find(|(path, _)| path.exists()) {
+        return Err(format!("{} already exists", path.display()));
+    }
+
+    // Registration first: if any of it fails, nothing has been created yet
+    let registrations = [
+        (
+            root.join("src/days/mod.rs"),
+            "//! Daily solutions, one module per day.\n",
+            None,
+            format!("pub mod day{:02};", day),
+        ),
+        (
+            root.join("src/lib.rs"),
+            "pub const DAYS: &[Day] = &[\n",
+            Some("];"),
+            format!("Day::of::<days::day{0:02}::Day{0:02}>(),", day),
+        ),
+        (
+            root.join("README.md"),
+            "<!-- progress:start -->",
+            Some("<!-- progress:end -->"),
+            format!("- [ ] Day {:02}", day),
+        ),
+    ];
+    let mut edited = Vec::new();
+    for (path, start, end, line) in &registrations {
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+        let updated = insert_line(&content, start, *end, line, day)
+            .map_err(|e| format!("{}: {}", path.display(), e))?;
+        edited.push((path.clone(), updated));
+    }
+
+    // Example tables are not kept in day order, the new one goes last
+    let manifest = root.join("inputs/examples/answers.toml");
+    let content = fs::read_to_string(&manifest)
+        .map_err(|e| format!("failed to read {}: {}", manifest.display(), e))?;
+    let table = format!("[day{:02}-1]", day);
+    if content.lines().any(|line| line.trim() == table) {
+        return Err(format!("{}: {} already exists", manifest.display(), table));
+    }
+    let content = format!(
+        "{}\n\n{}\n# part1 = ?\n# part2 = ?\n",
+        content.trim_end(),
+        table
+    );
+    edited.push((manifest, content));
+
+    let mut touched = Vec::new();
+    for (path, content) in new_files.iter().chain(&edited) {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("failed to create {}: {}", parent.display(), e))?;
+        }
+        fs::write(path, content)
+            .map_err(|e| format!("failed to write {}: {}", path.display(), e))?;
+        touched.push(path.clone());
+    }
+    Ok(touched)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_number() {
+        assert_eq!(day_number("pub mod day07;"), Some(7));
+        assert_eq!(day_number("    Day::of::<days::day12::Day12>(),"), Some(12));
+        assert_eq!(day_number("- [X] Day 03 ⭐⭐"), Some(3));
+        assert_eq!(day_number("<!-- progress:end -->"), None);
+    }
+
+    #[test]
+    fn test_insert_line() {
+        let document = "list = [\n    day01,\n    day04,\n];\n";
+        assert_eq!(
+            insert_line(document, "[\n", Some("];"), "day03,", 3).unwrap(),
+            "list = [\n    day01,\n    day03,\n    day04,\n];\n"
+        );
+        assert_eq!(
+            insert_line(document, "[\n", Some("];"), "day05,", 5).unwrap(),
+            "list = [\n    day01,\n    day04,\n    day05,\n];\n"
+        );
+        assert!(insert_line(document, "[\n", Some("];"), "day04,", 4).is_err());
+    }
+}
@@ -0,0 +1,413 @@
+//! Isolation of each day in a worker process.
+//!
+//! The runner re-executes itself with `--worker DAY`: the worker runs the day and
+//! writes its result on stdout, one `key value` line per field. The parent enforces
+//! the wall-clock timeout, and the worker applies the memory limit to itself, so a
+//! runaway, panicking or memory-hungry day only takes its own process down.
+
+use crate::alloc::AllocStats;
+use crate::bench::Stats;
+use crate::{DayResult, Options, Status, run_day};
+use advent_of_code_2025::day;
+use std::backtrace::Backtrace;
+use std::env;
+use std::io::{self, Read};
+use std::panic;
+use std::process::{Command, ExitCode, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Hidden flag turning the runner into a worker for a single day.
+pub const WORKER_FLAG: &str = "--worker";
+
+/// Default wall-clock limit of a day, outside of benchmark mode.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Names of the steps of a day, in order.
+const STEPS: [&str; 3] = ["parse", "part1", "part2"];
+
+/// How often the parent checks whether the worker is done.
+const POLL_INTERVAL: Duration = Duration::from_millis(1);
+
+/// Parses a memory size such as "512M", "2G" or "1048576" (bytes).
+pub fn parse_size(s: &str) -> Option<u64> {
+    let (value, unit) = s.split_at(s.find(|c: char| c.is_ascii_alphabetic()).unwrap_or(s.len()));
+    let value: u64 = value.parse().ok()?;
+    let scale: u64 = match unit {
+        "" => 1,
+        "K" | "k" => 1 << 10,
+        "M" | "m" => 1 << 20,
+        "G" | "g" => 1 << 30,
+        _ => return None,
+    };
+    value.checked_mul(scale)
+}
+
+/// Limits the address space of the current process to `bytes`.
+#[cfg(target_os = "linux")]
+fn limit_memory(bytes: u64) -> Result<(), String> {
+    use std::ffi::{c_int, c_ulong};
+
+    #[repr(C)]
+    struct RLimit {
+        current: c_ulong,
+        max: c_ulong,
+    }
+
+    const RLIMIT_AS: c_int = 9;
+
+    unsafe extern "C" {
+        fn setrlimit(resource: c_int, limit: *const RLimit) -> c_int;
+    }
+
+    let bytes = c_ulong::try_from(bytes).unwrap_or(c_ulong::MAX);
+    let limit = RLimit {
+        current: bytes,
+        max: bytes,
+    };
+    // SAFETY: `limit` is a valid `struct rlimit` for the duration of the call.
+    if unsafe { setrlimit(RLIMIT_AS, &limit) } == 0 {
+        Ok(())
+    } else {
+        Err(format!(
+            "failed to limit memory: {}",
+            io::Error::last_os_error()
+        ))
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn limit_memory(_bytes: u64) -> Result<(), String> {
+    Err("--memory is only supported on Linux".to_string())
+}
+
+/// Escapes backslashes and newlines so that a value fits on one line.
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some(c) => out.push(c),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+/// Frames of a backtrace that belong to the solutions, without the std and runner noise.
+fn solution_frames(backtrace: &Backtrace) -> Vec<String> {
+    let mut frames = Vec::new();
+    let mut keep = false;
+    for line in backtrace.to_string().lines() {
+        let line = line.trim();
+        if line
+            .split_once(": ")
+            .is_some_and(|(i, _)| i.parse::<u32>().is_ok())
+        {
+            keep = line.contains("advent_of_code_2025::");
+        }
+        if keep {
+            frames.push(line.to_string());
+        }
+    }
+    frames
+}
+
+/// Reports panics on stdout, where the parent reads them.
+fn install_panic_hook() {
+    panic::set_hook(Box::new(|info| {
+        let payload = info.payload();
+        let message = payload
+            .downcast_ref::<&str>()
+            .copied()
+            .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+            .unwrap_or("Box<dyn Any>");
+        let message = match info.location() {
+            Some(location) => format!("{} ({})", message, location),
+            None => message.to_string(),
+        };
+        println!("panic {}", escape(&message));
+        for frame in solution_frames(&Backtrace::force_capture()) {
+            println!("frame {}", frame);
+        }
+    }));
+}
+
+fn encode_stats(out: &mut String, key: &str, stats: Option<Stats>) {
+    if let Some(s) = stats {
+        out.push_str(&format!(
+            "{} {} {} {} {} {} {}\n",
+            key,
+            s.samples,
+            s.min.as_nanos(),
+            s.median.as_nanos(),
+            s.mean.as_nanos(),
+            s.stddev.as_nanos(),
+            s.outliers
+        ));
+    }
+}
+
+fn decode_stats(value: &str) -> Option<Stats> {
+    let fields: Vec<u64> = value
+        .split(' ')
+        .map(|f| f.parse())
+        .collect::<Result<_, _>>()
+        .ok()?;
+    let [samples, min, median, mean, stddev, outliers] = fields[..] else {
+        return None;
+    };
+    Some(Stats {
+        samples: samples as usize,
+        min: Duration::from_nanos(min),
+        median: Duration::from_nanos(median),
+        mean: Duration::from_nanos(mean),
+        stddev: Duration::from_nanos(stddev),
+        outliers: outliers as usize,
+    })
+}
+
+/// Serialises the result of a day for the parent.
+fn encode(result: &DayResult) -> String {
+    let mut out = String::new();
+    match &result.status {
+        Status::Missing => out.push_str("status missing\n"),
+        Status::Failed(message) => out.push_str(&format!("failed {}\n", escape(message))),
+        _ => out.push_str("status success\n"),
+    }
+    for (i, answer) in result.answers.iter().enumerate() {
+        if let Some(answer) = answer {
+            out.push_str(&format!("answer{} {}\n", i + 1, answer));
+        }
+    }
+    encode_stats(&mut out, "parse", result.parse);
+    encode_stats(&mut out, "part1", result.part1);
+    encode_stats(&mut out, "part2", result.part2);
+    for (step, allocs) in STEPS.iter().zip(result.allocs) {
+        if let Some(a) = allocs {
+            out.push_str(&format!(
+                "alloc {} {} {} {}\n",
+                step, a.peak, a.bytes, a.count
+            ));
+        }
+    }
+    out
+}
+
+/// Fills `result` from the output of a worker; returns whether a status was reported.
+fn decode(output: &str, result: &mut DayResult) -> Result<bool, String> {
+    let mut reported = false;
+    let mut panic = None;
+    let mut frames = Vec::new();
+
+    for line in output.lines() {
+        let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+        let invalid = || format!("invalid worker output {:?}", line);
+        match key {
+            "status" => {
+                reported = true;
+                result.status = match value {
+                    "success" => Status::Success,
+                    "missing" => Status::Missing,
+                    _ => return Err(invalid()),
+                };
+            }
+            "failed" => {
+                reported = true;
+                result.status = Status::Failed(unescape(value));
+            }
+            "answer1" | "answer2" => {
+                let part = usize::from(key == "answer2");
+                result.answers[part] = Some(value.parse().map_err(|_| invalid())?);
+            }
+            "parse" => result.parse = Some(decode_stats(value).ok_or_else(invalid)?),
+            "part1" => result.part1 = Some(decode_stats(value).ok_or_else(invalid)?),
+            "part2" => result.part2 = Some(decode_stats(value).ok_or_else(invalid)?),
+            "alloc" => {
+                let fields: Vec<&str> = value.split(' ').collect();
+                let [step, peak, bytes, count] = fields[..] else {
+                    return Err(invalid());
+                };
+                let step = STEPS.iter().position(|&s| s == step).ok_or_else(invalid)?;
+                let number = |field: &str| field.parse().map_err(|_| invalid());
+                result.allocs[step] = Some(AllocStats {
+                    peak: number(peak)?,
+                    bytes: number(bytes)?,
+                    count: number(count)?,
+                });
+            }
+            "panic" => panic = Some(unescape(value)),
+            "frame" => frames.push(value.to_string()),
+            _ => return Err(invalid()),
+        }
+    }
+
+    if let Some(message) = panic {
+        result.status = Status::Panicked {
+            message,
+            backtrace: frames,
+        };
+        return Ok(true);
+    }
+    Ok(reported)
+}
+
+/// Entry point of a worker: runs the single selected day and reports it on stdout.
+pub fn serve(options: &Options) -> ExitCode {
+    let Some(day) = options.days.first().and_then(|&n| day(n)) else {
+        eprintln!("{} expects a registered day", WORKER_FLAG);
+        return ExitCode::from(2);
+    };
+    install_panic_hook();
+    if let Some(bytes) = options.memory
+        && let Err(e) = limit_memory(bytes)
+    {
+        eprintln!("{}", e);
+        return ExitCode::FAILURE;
+    }
+
+    print!("{}", encode(&run_day(day, options)));
+    ExitCode::SUCCESS
+}
+
+/// Arguments forwarding the relevant options to a worker.
+fn worker_args(day: u8, options: &Options) -> Vec<String> {
+    let mut args = vec![WORKER_FLAG.to_string(), day.to_string()];
+    if let Some(part) = options.part {
+        args.extend(["--part".to_string(), part.to_string()]);
+    }
+    if let Some(config) = &options.bench {
+        args.extend([
+            "--warmup".to_string(),
+            config.warmup.to_string(),
+            "--samples".to_string(),
+            config.samples.to_string(),
+            "--time".to_string(),
+            format!("{}us", config.budget.as_micros()),
+        ]);
+    }
+    if let Some(bytes) = options.memory {
+        args.extend(["--memory".to_string(), bytes.to_string()]);
+    }
+    args
+}
+
+/// Reads a pipe to the end on a separate thread, so that the worker never blocks on it.
+fn drain(mut pipe: impl Read + Send + 'static) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut text = String::new();
+        let _ = pipe.read_to_string(&mut text);
+        text
+    })
+}
+
+/// Runs a day in a worker process, killing it when it exceeds the timeout.
+pub fn run_isolated(day: &advent_of_code_2025::Day, options: &Options) -> DayResult {
+    let mut result = DayResult::new(day);
+    let fail = |mut result: DayResult, message: String| {
+        result.status = Status::Failed(message);
+        result
+    };
+
+    let exe = match env::current_exe() {
+        Ok(exe) => exe,
+        Err(e) => return fail(result, format!("failed to locate the runner: {}", e)),
+    };
+    let mut child = match Command::new(exe)
+        .args(worker_args(day.number, options))
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(e) => return fail(result, format!("failed to start the worker: {}", e)),
+    };
+    let stdout = drain(child.stdout.take().expect("stdout is piped"));
+    let stderr = drain(child.stderr.take().expect("stderr is piped"));
+
+    let start = Instant::now();
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break Some(status),
+            Ok(None) if options.timeout.is_some_and(|t| start.elapsed() >= t) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                break None;
+            }
+            Ok(None) => thread::sleep(POLL_INTERVAL),
+            Err(e) => return fail(result, format!("failed to wait for the worker: {}", e)),
+        }
+    };
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+
+    let Some(status) = status else {
+        let timeout = options.timeout.unwrap_or_default();
+        result.status = Status::Timeout(format!("timed out after {:?}", timeout));
+        return result;
+    };
+    match decode(&stdout, &mut result) {
+        Ok(true) => result,
+        Ok(false) => {
+            let detail = stderr.lines().find(|l| !l.trim().is_empty());
+            let detail = detail.unwrap_or_default();
+            fail(result, format!("worker exited with {}: {}", status, detail))
+        }
+        Err(e) => fail(result, e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_size() {
+        assert_eq!(parse_size("512M"), Some(512 << 20));
+        assert_eq!(parse_size("2G"), Some(2 << 30));
+        assert_eq!(parse_size("4096"), Some(4096));
+        assert_eq!(parse_size("1T"), None);
+        assert_eq!(parse_size("M"), None);
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let day = day(1).unwrap();
+        let mut result = DayResult::new(day);
+        result.status = Status::Failed("line 1\nline 2 \\ end".to_string());
+        result.answers = [Some(962), None];
+        result.parse = Some(Stats::single(Duration::from_nanos(1_500)));
+        result.allocs[0] = Some(AllocStats {
+            peak: 64,
+            bytes: 96,
+            count: 2,
+        });
+
+        let mut decoded = DayResult::new(day);
+        assert_eq!(decode(&encode(&result), &mut decoded), Ok(true));
+        assert_eq!(decoded.status.message(), result.status.message());
+        assert_eq!(decoded.answers, result.answers);
+        assert_eq!(decoded.parse, result.parse);
+        assert_eq!(decoded.part1, None);
+        assert_eq!(decoded.allocs, result.allocs);
+
+        let panicked = "panic boom (src/days/day10.rs:1:1)\nframe 3: advent_of_code_2025::days::day10::part2\n";
+        assert_eq!(decode(panicked, &mut decoded), Ok(true));
+        assert!(matches!(
+            decoded.status,
+            Status::Panicked { ref message, ref backtrace }
+                if message.starts_with("boom") && backtrace.len() == 1
+        ));
+        assert!(decode("", &mut decoded).is_ok_and(|reported| !reported));
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{InputError, Solution, input::parse_field, lines};
+
+const DIAL_SIZE: i32 = 100;
+const START: i32 = 50;
+
+#[derive(Clone, Copy)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+/// A single rotation of the dial
+type Rotation = (Direction, i32);
+
+/// Parse each line ("L68", "R14") into a rotation
+fn parse_rotations(input: &str) -> Result<Vec<Rotation>, InputError> {
+    lines(input)
+        .map(|line| {
+            let direction = match line.as_bytes().first() {
+                Some(b'L') => Direction::Left,
+                Some(b'R') => Direction::Right,
+                _ => return Err(InputError::malformed(input, line, "expected L or R")),
+            };
+            Ok((direction, parse_field(input, &line[1..])?))
+        })
+        .collect()
+}
+
+/// Counts how many times the dial ends exactly on zero after each move.
+fn part1(input: &[Rotation]) -> u64 {
+    let mut position = START;
+    let mut zero_count = 0;
+
+    for &(direction, distance) in input {
+        let steps = distance.rem_euclid(DIAL_SIZE);
+
+        position = match direction {
+            Direction::Left => (position - steps).rem_euclid(DIAL_SIZE),
+            Direction::Right => (position + steps).rem_euclid(DIAL_SIZE),
+        };
+
+        if position == 0 {
+            zero_count += 1;
+        }
+    }
+
+    zero_count
+}
+
+/// Counts how many times the dial crosses zero during all movements.
+fn part2(input: &[Rotation]) -> u64 {
+    let mut position = START;
+    let mut zero_count = 0u64;
+
+    for &(direction, distance) in input {
+        let distance_to_zero = match direction {
+            Direction::Left => {
+                if position == 0 {
+                    DIAL_SIZE
+                } else {
+                    position
+                }
+            }
+            Direction::Right => {
+                if position == 0 {
+                    DIAL_SIZE
+                } else {
+                    DIAL_SIZE - position
+                }
+            }
+        };
+
+        if distance >= distance_to_zero {
+            zero_count += (1 + (distance - distance_to_zero) / DIAL_SIZE) as u64;
+        }
+
+        let steps = distance.rem_euclid(DIAL_SIZE);
+        position = match direction {
+            Direction::Left => (position - steps).rem_euclid(DIAL_SIZE),
+            Direction::Right => (position + steps).rem_euclid(DIAL_SIZE),
+        };
+    }
+
+    zero_count
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Secret Entrance";
+    const SUMMARY: &'static [&'static str] = &[
+        "Compter les fois où le cadran s'arrête exactement sur 0",
+        "Compter les fois où le cadran traverse 0 pendant les mouvements",
+    ];
+
+    type Input<'a> = Vec<Rotation>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, InputError> {
+        parse_rotations(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Option<u64> {
+        Some(part2(input))
+    }
+}
@@ -0,0 +1,109 @@
+use crate::{InputError, Solution, input::parse_fields};
+use std::ops::RangeInclusive;
+
+/// Checks if a number has even-length digits split in two equal parts.
+fn has_equal_parts(n: u64) -> bool {
+    let digits = n.to_string();
+    let len = digits.len();
+
+    if !len.is_multiple_of(2) {
+        return false;
+    }
+
+    let mid = len / 2;
+    digits[..mid] == digits[mid..]
+}
+
+/// Checks if a number can be formed by repeating a pattern at least twice.
+fn has_repeating_pattern(n: u64) -> bool {
+    let digits = n.to_string();
+    let len = digits.len();
+
+    for pattern_size in 1..=len / 2 {
+        if !len.is_multiple_of(pattern_size) {
+            continue;
+        }
+
+        let repeat_count = len / pattern_size;
+        if repeat_count < 2 {
+            continue;
+        }
+
+        let pattern = &digits[..pattern_size];
+        if pattern.repeat(repeat_count) == digits {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Parse the comma-separated "start-end" ranges, kept as given: an ID in two
+/// overlapping ranges is counted twice
+fn parse_ranges(input: &str) -> Result<Vec<RangeInclusive<u64>>, InputError> {
+    input
+        .split(',')
+        .map(str::trim)
+        .filter(|range| !range.is_empty())
+        .map(|range| {
+            let [start, end] = parse_fields(input, range, '-')?;
+            Ok(start..=end)
+        })
+        .collect()
+}
+
+/// Solves the problem by applying the validator function over the given ranges.
+fn solve<F>(input: &[RangeInclusive<u64>], validator: F) -> u64
+where
+    F: Fn(u64) -> bool,
+{
+    input
+        .iter()
+        .map(|range| range.clone().filter(|&n| validator(n)).sum::<u64>())
+        .sum()
+}
+
+fn part1(input: &[RangeInclusive<u64>]) -> u64 {
+    solve(input, has_equal_parts)
+}
+
+fn part2(input: &[RangeInclusive<u64>]) -> u64 {
+    solve(input, has_repeating_pattern)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Gift Shop";
+    const SUMMARY: &'static [&'static str] = &[
+        "Somme des nombres avec deux moitiés identiques (ex: 1212 -> 12|12)",
+        "Somme des nombres avec un motif répété au moins 2 fois (ex: 123123)",
+    ];
+
+    type Input<'a> = Vec<RangeInclusive<u64>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, InputError> {
+        parse_ranges(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Option<u64> {
+        Some(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overlapping_ranges() {
+        // 11 and 22 are in both ranges, 33 only in the second one
+        let input = Day02::parse("11-22,10-33").unwrap();
+        assert_eq!(part1(&input), 2 * (11 + 22) + 33);
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{InputError, Solution, non_empty_lines};
+
+/// Find the maximum joltage that can be formed with the given number of digits
+fn find_max_joltage(bank: &[u8], num_digits: usize) -> u64 {
+    let mut best: Vec<u64> = vec![0; num_digits];
+
+    for &digit in bank {
+        let d = (digit - b'0') as u64;
+
+        for i in (1..num_digits).rev() {
+            let new_val = best[i - 1] * 10 + d;
+            best[i] = best[i].max(new_val);
+        }
+
+        best[0] = best[0].max(d);
+    }
+
+    best[num_digits - 1] as u64
+}
+
+/// Parse the banks of batteries, one line of ASCII digits each
+fn parse_banks(input: &str) -> Result<Vec<&[u8]>, InputError> {
+    non_empty_lines(input)
+        .map(str::trim)
+        .map(|line| match line.find(|c: char| !c.is_ascii_digit()) {
+            Some(i) => Err(InputError::malformed(input, &line[i..], "expected a digit")),
+            None => Ok(line.as_bytes()),
+        })
+        .collect()
+}
+
+/// Solve the problem for the given number of digits
+fn solve(num_digits: usize, input: &[&[u8]]) -> u64 {
+    input
+        .iter()
+        .map(|line| find_max_joltage(line, num_digits))
+        .sum()
+}
+
+/// Solve the problem for the 2 of digits
+fn part1(input: &[&[u8]]) -> u64 {
+    solve(2, input)
+}
+
+/// Solve the problem for the 12 of digits
+fn part2(input: &[&[u8]]) -> u64 {
+    solve(12, input)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Lobby";
+    const SUMMARY: &'static [&'static str] = &[
+        "Max joltage avec 2 digits (garder le meilleur préfixe en O(n))",
+        "Max joltage avec 12 digits (même algo)",
+    ];
+
+    type Input<'a> = Vec<&'a [u8]>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, InputError> {
+        parse_banks(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Option<u64> {
+        Some(part2(input))
+    }
+}
@@ -0,0 +1,95 @@
+use crate::grid::Pos;
+use crate::{Grid, InputError, Solution};
+
+/// Parse the input grid of rolls (@) and empty cells (.)
+fn parse_grid(input: &str) -> Result<Grid<u8>, InputError> {
+    Grid::parse(input, None, |cell| match cell {
+        "." | "@" => Ok(cell.as_bytes()[0]),
+        _ => Err(InputError::malformed(input, cell, "expected . or @")),
+    })
+}
+
+/// Build a neighbor count grid
+fn build_neighbor_counts(grid: &Grid<u8>) -> Grid<u8> {
+    let mut counts = Grid::new(grid.width(), grid.height(), 0u8);
+    for (pos, &cell) in grid.iter() {
+        if cell != b'@' {
+            continue;
+        }
+        for neighbor in grid.neighbors8(pos) {
+            counts[neighbor] += 1;
+        }
+    }
+    counts
+}
+
+/// Check if a cell is accessible based on the grid and neighbor counts
+fn is_accessible(grid: &Grid<u8>, counts: &Grid<u8>, pos: Pos) -> bool {
+    grid[pos] == b'@' && counts[pos] < 4
+}
+
+/// Find all accessible cells in the grid
+fn find_accessible(grid: &Grid<u8>, counts: &Grid<u8>) -> Vec<Pos> {
+    grid.positions()
+        .filter(|&pos| is_accessible(grid, counts, pos))
+        .collect()
+}
+
+/// Count accessible cells in the grid
+fn part1(grid: &Grid<u8>) -> u64 {
+    let counts = build_neighbor_counts(grid);
+    find_accessible(grid, &counts).len() as u64
+}
+
+/// Remove accessible cells iteratively and count them while updating neighbor counts
+fn part2(input: &Grid<u8>) -> u64 {
+    let mut grid = input.clone();
+    let mut counts = build_neighbor_counts(&grid);
+
+    let mut stack = find_accessible(&grid, &counts);
+    let mut total_removed = 0;
+
+    while let Some(pos) = stack.pop() {
+        if grid[pos] != b'@' {
+            continue;
+        }
+
+        grid[pos] = b'.';
+        total_removed += 1;
+
+        for neighbor in input.neighbors8(pos) {
+            counts[neighbor] -= 1;
+
+            if is_accessible(&grid, &counts, neighbor) {
+                stack.push(neighbor);
+            }
+        }
+    }
+
+    total_removed
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Printing Department";
+    const SUMMARY: &'static [&'static str] = &[
+        "Compter les rouleaux @ accessibles (< 4 voisins @)",
+        "Retirer les rouleaux accessibles en boucle jusqu'à stabilisation (propagation BFS)",
+    ];
+
+    type Input<'a> = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, InputError> {
+        parse_grid(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Option<u64> {
+        Some(part2(input))
+    }
+}
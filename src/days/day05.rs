@@ -0,0 +1,90 @@
+use crate::{InputError, IntervalSet, Solution, input::parse_field, input::parse_fields, lines};
+
+/// (Fresh ID ranges, available IDs)
+type Inventory = (IntervalSet, Vec<u64>);
+
+/// Parse the input into fresh ranges and IDs
+fn parse_input(input: &str) -> Result<Inventory, InputError> {
+    let mut lines = lines(input);
+
+    let ranges: IntervalSet = lines
+        .by_ref()
+        .take_while(|line| !line.is_empty())
+        .map(|line| {
+            let [start, end] = parse_fields(input, line, '-')?;
+            Ok(start..=end)
+        })
+        .collect::<Result<_, _>>()?;
+    if ranges.len() > u64::MAX as u128 {
+        return Err(InputError::malformed(
+            input,
+            input,
+            "ranges cover all 2^64 IDs, too many to count",
+        ));
+    }
+
+    let ids = lines
+        .filter(|s| !s.is_empty())
+        .map(|s| parse_field(input, s))
+        .collect::<Result<_, _>>()?;
+
+    Ok((ranges, ids))
+}
+
+/// Count IDs that are fresh (within ranges)
+fn part1((ranges, ids): &Inventory) -> u64 {
+    ids.iter().filter(|&&id| ranges.contains(id)).count() as u64
+}
+
+/// Count total unique IDs covered by the ranges (ignoring given IDs)
+fn part2((ranges, _ids): &Inventory) -> u64 {
+    // Parsing rejects ranges covering all 2^64 IDs, the only count that does not fit
+    ranges.len() as u64
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Cafeteria";
+    const SUMMARY: &'static [&'static str] = &[
+        "Compter les IDs disponibles qui sont dans un range 'fresh'",
+        "Compter le nombre total d'IDs couverts par les ranges fusionnés",
+    ];
+
+    type Input<'a> = Inventory;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, InputError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Option<u64> {
+        Some(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_malformed_range() {
+        let err = Day05::parse("3-5\n10-x4\n\n1").unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 4: invalid number \"x4\"");
+    }
+
+    #[test]
+    fn test_full_domain() {
+        let almost = Day05::parse("1-18446744073709551615\n\n1").unwrap();
+        assert_eq!(part2(&almost), u64::MAX);
+        let err = Day05::parse("0-5\n1-18446744073709551615\n\n1").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: ranges cover all 2^64 IDs, too many to count"
+        );
+    }
+}
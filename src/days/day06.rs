@@ -0,0 +1,114 @@
+use crate::{Grid, InputError, Solution};
+
+/// Build the worksheet grid, padding short lines with spaces
+fn build_grid(input: &str) -> Result<Grid<u8>, InputError> {
+    let grid = Grid::parse(input, Some(b' '), |cell| Ok(cell.as_bytes()[0]))?;
+    if grid.height() < 2 {
+        return Err(InputError::at_end(
+            input,
+            "expected numbers above an operator row",
+        ));
+    }
+    Ok(grid)
+}
+
+/// Find problems (operations with their column ranges) in the grid
+fn find_problems(grid: &Grid<u8>) -> Vec<(u8, usize, usize)> {
+    // Check if a column is a separator (all spaces)
+    let is_separator = |col: usize| grid.column(col).all(|&cell| cell == b' ');
+    let operators = grid.row(grid.height() - 1);
+
+    let mut problems = Vec::new();
+    let mut col = 0;
+
+    while col < grid.width() {
+        if is_separator(col) {
+            col += 1;
+            continue;
+        }
+
+        let start_col = col;
+        while col < grid.width() && !is_separator(col) {
+            col += 1;
+        }
+
+        let op = operators[start_col..col]
+            .iter()
+            .copied()
+            .find(|&cell| cell == b'+' || cell == b'*')
+            .unwrap_or(b'+');
+
+        problems.push((op, start_col, col));
+    }
+
+    problems
+}
+
+/// Apply the operation to the list of numbers
+fn apply_op(op: u8, nums: Vec<u64>) -> u64 {
+    match op {
+        b'+' => nums.iter().sum(),
+        b'*' => nums.iter().product(),
+        _ => 0,
+    }
+}
+
+/// Read the digits of `cells` as a number, skipping spaces
+fn number<'a>(cells: impl Iterator<Item = &'a u8>) -> u64 {
+    cells
+        .filter(|cell| cell.is_ascii_digit())
+        .fold(0, |acc, &d| acc * 10 + (d - b'0') as u64)
+}
+
+fn solve<F>(grid: &Grid<u8>, extract_nums: F) -> u64
+where
+    F: Fn(&Grid<u8>, usize, usize) -> Vec<u64>,
+{
+    find_problems(grid)
+        .iter()
+        .map(|&(op, start, end)| apply_op(op, extract_nums(grid, start, end)))
+        .sum()
+}
+
+fn part1(input: &Grid<u8>) -> u64 {
+    solve(input, |grid, start, end| {
+        grid.rows()
+            .take(grid.height() - 1) // skip the operator row
+            .map(|row| number(row[start..end].iter()))
+            .collect()
+    })
+}
+
+fn part2(input: &Grid<u8>) -> u64 {
+    solve(input, |grid, start, end| {
+        (start..end)
+            .rev() // right to left
+            .map(|c| number(grid.column(c).take(grid.height() - 1)))
+            .collect()
+    })
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Trash Compactor";
+    const SUMMARY: &'static [&'static str] = &[
+        "Lecture horizontale des nombres, puis + ou * selon l'opérateur",
+        "Lecture verticale des colonnes de droite à gauche",
+    ];
+
+    type Input<'a> = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, InputError> {
+        build_grid(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Option<u64> {
+        Some(part2(input))
+    }
+}
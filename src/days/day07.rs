@@ -0,0 +1,85 @@
+use crate::{Grid, InputError, Solution, lines};
+
+/// Parse the manifold, checking the start point and that every row is complete
+fn parse_manifold(input: &str) -> Result<Grid<u8>, InputError> {
+    let first = lines(input).next().unwrap_or_default();
+    if !first.contains('S') {
+        return Err(InputError::malformed(
+            input,
+            first,
+            "expected a starting point S",
+        ));
+    }
+    Grid::parse(input, None, |cell| Ok(cell.as_bytes()[0]))
+}
+
+/// Simulate the propagation of beams through the grid
+/// Returns (number of splitters hit, total distinct timelines at the bottom)
+/// The grid contains:
+/// - 'S' : starting point of the beam
+/// - '^' : splitter that divides the beam into two (left and right)
+/// - '.' : empty space where the beam continues straight down
+/// - any other character : blocks the beam
+fn propagate_beams(grid: &Grid<u8>) -> (u64, u64) {
+    let width = grid.width();
+    let start_col = grid.row(0).iter().position(|&cell| cell == b'S').unwrap();
+
+    let mut beams = vec![0u64; width];
+    beams[start_col] = 1;
+    let mut split_count = 0u64;
+
+    for row in grid.rows().skip(1) {
+        let mut next_beams = vec![0u64; width];
+
+        for (col, &timelines) in beams.iter().enumerate() {
+            if timelines == 0 {
+                continue;
+            }
+
+            match row[col] {
+                b'^' => {
+                    split_count += 1;
+                    if col > 0 {
+                        next_beams[col - 1] += timelines;
+                    }
+                    if col + 1 < width {
+                        next_beams[col + 1] += timelines;
+                    }
+                }
+                b'.' | b'S' => {
+                    next_beams[col] += timelines;
+                }
+                _ => {}
+            }
+        }
+
+        beams = next_beams;
+    }
+
+    (split_count, beams.iter().sum())
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Laboratories";
+    const SUMMARY: &'static [&'static str] = &[
+        "Nombre de splitters ^ touchés par les faisceaux",
+        "Nombre de chemins distincts (timelines) en fin de grille",
+    ];
+
+    type Input<'a> = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, InputError> {
+        parse_manifold(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> u64 {
+        propagate_beams(input).0
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Option<u64> {
+        Some(propagate_beams(input).1)
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{DisjointSet, InputError, Point3, Solution, lines};
+
+/// Coordinates are below 2^31, so that squared distances and the part 2 product fit a `u64`
+const MAX_COORDINATE: i64 = (1 << 31) - 1;
+
+/// Parses one "x,y,z" junction box position per line
+fn parse_points(input: &str) -> Result<Vec<Point3>, InputError> {
+    let points: Vec<Point3> = lines(input)
+        .filter(|s| !s.is_empty())
+        .map(|line| {
+            let point = Point3::parse(input, line)?;
+            if [point.x, point.y, point.z]
+                .iter()
+                .any(|c| !(0..=MAX_COORDINATE).contains(c))
+            {
+                return Err(InputError::malformed(
+                    input,
+                    line,
+                    format!("coordinates must be between 0 and {}", MAX_COORDINATE),
+                ));
+            }
+            Ok(point)
+        })
+        .collect::<Result<_, _>>()?;
+    if points.is_empty() {
+        return Err(InputError::at_end(input, "expected junction boxes"));
+    }
+    Ok(points)
+}
+
+/// Computes all pairwise squared distances between points and returns them sorted
+fn compute_sorted_distances(points: &[Point3]) -> Vec<(u64, usize, usize)> {
+    let n = points.len();
+    let mut distances: Vec<(u64, usize, usize)> = Vec::with_capacity(n * (n - 1) / 2);
+    for i in 0..n {
+        for j in (i + 1)..n {
+            distances.push((points[i].squared_distance(points[j]), i, j));
+        }
+    }
+    distances.sort_unstable_by_key(|d| d.0);
+    distances
+}
+
+fn part1(points: &[Point3]) -> u64 {
+    part1_with_n_connections(points, 1000)
+}
+
+fn part1_with_n_connections(points: &[Point3], num_connections: usize) -> u64 {
+    let mut distances = compute_sorted_distances(points);
+
+    // Keep only the smallest distances
+    distances.truncate(num_connections);
+
+    // Each point starts as its own group
+    let mut groups = DisjointSet::new(points.len());
+
+    // Connect each pair, merging their groups
+    for &(_, i, j) in &distances {
+        groups.union(i, j);
+    }
+
+    let mut sizes: Vec<usize> = groups.sizes().collect();
+    sizes.sort_unstable_by(|a, b| b.cmp(a));
+
+    sizes.iter().take(3).map(|&s| s as u64).product()
+}
+
+fn part2(points: &[Point3]) -> u64 {
+    let distances = compute_sorted_distances(points);
+
+    // Each point starts as its own group
+    let mut groups = DisjointSet::new(points.len());
+
+    // Connect pairs until all points are in one circuit
+    for &(_, i, j) in &distances {
+        if groups.union(i, j) && groups.count() == 1 {
+            return points[i].x as u64 * points[j].x as u64;
+        }
+    }
+    0
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Playground";
+    const SUMMARY: &'static [&'static str] = &[
+        "Connecter les 1000 paires les plus proches, produit des 3 plus grands circuits",
+        "Connecter jusqu'à un seul circuit, produit des X des 2 dernières boîtes",
+    ];
+
+    type Input<'a> = Vec<Point3>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, InputError> {
+        parse_points(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Option<u64> {
+        Some(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1_example() {
+        // The example only makes 10 connections, where the real input makes 1000
+        let example = include_str!("../../inputs/examples/day08-1.txt");
+        let input = Day08::parse(example).unwrap();
+        assert_eq!(part1_with_n_connections(&input, 10), 40);
+    }
+
+    #[test]
+    fn test_invalid_points() {
+        let err = Day08::parse("1,2,3\n4,-5,6").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: coordinates must be between 0 and 2147483647"
+        );
+        let err = Day08::parse("\n\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 3, column 1: expected junction boxes");
+    }
+}
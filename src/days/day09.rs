@@ -0,0 +1,100 @@
+use crate::{InputError, Point2, Rect, Segment, Solution, lines};
+use std::cmp::Reverse;
+
+/// (Red tiles in order, polygon edges joining each tile to the next one)
+type Floor = (Vec<Point2>, Vec<Segment>);
+
+/// Parse input lines into a list of tile coordinates and the edges between them
+fn parse_floor(input: &str) -> Result<Floor, InputError> {
+    let lines: Vec<&str> = lines(input).filter(|s| !s.is_empty()).collect();
+    let tiles = lines
+        .iter()
+        .map(|line| Point2::parse(input, line))
+        .collect::<Result<Vec<_>, _>>()?;
+    let segments = lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let next = tiles[(i + 1) % tiles.len()];
+            Segment::new(tiles[i], next).ok_or_else(|| {
+                InputError::malformed(
+                    input,
+                    line,
+                    format!("tile not in line with the next one ({},{})", next.x, next.y),
+                )
+            })
+        })
+        .collect::<Result<_, _>>()?;
+    Ok((tiles, segments))
+}
+
+/// Find the largest rectangle defined by two red tiles at opposite corners
+fn part1((tiles, _segments): &Floor) -> u64 {
+    tiles
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &a)| {
+            tiles[i + 1..]
+                .iter()
+                .map(move |&b| Rect::from_corners(a, b).area())
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Checks that no segment crosses the strict interior of the rectangle.
+///
+/// A rectangle is valid if it lies entirely within the polygon.
+/// Since both corners are red tiles (on the polygon boundary),
+/// we only need to verify that no polygon edge cuts through the rectangle's interior.
+fn rectangle_fits(rect: &Rect, segments: &[Segment]) -> bool {
+    !segments
+        .iter()
+        .any(|segment| rect.interior_overlaps(segment.bounds()))
+}
+
+/// Sorts pairs by descending area and returns the first one whose rectangle is valid.
+fn part2((tiles, segments): &Floor) -> u64 {
+    let mut rects: Vec<Rect> = tiles
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &a)| {
+            tiles[i + 1..]
+                .iter()
+                .map(move |&b| Rect::from_corners(a, b))
+        })
+        .collect();
+
+    rects.sort_unstable_by_key(|rect| Reverse(rect.area()));
+
+    rects
+        .into_iter()
+        .find(|rect| rectangle_fits(rect, segments))
+        .map(|rect| rect.area())
+        .unwrap_or(0)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Movie Theater";
+    const SUMMARY: &'static [&'static str] = &[
+        "Plus grand rectangle avec 2 tuiles rouges en coins opposés",
+        "Plus grand rectangle entièrement dans le polygone rouge/vert (le polygone est formé en reliant les tuiles rouges)",
+    ];
+
+    type Input<'a> = Floor;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, InputError> {
+        parse_floor(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Option<u64> {
+        Some(part2(input))
+    }
+}
@@ -0,0 +1,283 @@
+use crate::linalg::{Matrix, Rational, Solutions};
+use crate::{InputError, Solution, input::parse_field, lines};
+
+/// (Target Lights Mask, Target Joltage Vector, Buttons List)
+type ParsedData = (u64, Vec<usize>, Vec<Vec<usize>>);
+
+/// Lights are stored as bits of a `u64`
+const MAX_LIGHTS: usize = 64;
+
+/// Part 1 tries every subset of buttons, so more than 2^24 of them would not finish
+const MAX_BUTTONS: usize = 24;
+
+/// Parse a comma-separated list of numbers
+fn parse_list(input: &str, list: &str) -> Result<Vec<usize>, InputError> {
+    list.split(',')
+        .map(|n| parse_field(input, n.trim()))
+        .collect()
+}
+
+/// Parse input lines into structured data
+fn parse_input(input: &str) -> Result<Vec<ParsedData>, InputError> {
+    lines(input)
+        .filter(|s| !s.is_empty())
+        .map(|line| {
+            let Some((bracket_start, bracket_end)) = line.find('[').and_then(|start| {
+                let end = line[start..].find(']')?;
+                Some((start, start + end))
+            }) else {
+                return Err(InputError::malformed(input, line, "expected [lights]"));
+            };
+            let lights = &line[bracket_start + 1..bracket_end];
+            if lights.len() > MAX_LIGHTS {
+                return Err(InputError::malformed(
+                    input,
+                    lights,
+                    format!("more than {} lights", MAX_LIGHTS),
+                ));
+            }
+            let target_lights = line[bracket_start + 1..bracket_end]
+                .chars()
+                .enumerate()
+                .filter(|(_, c)| *c == '#')
+                .fold(0, |acc, (i, _)| acc | (1 << i));
+
+            let rest = &line[bracket_end + 1..];
+            let brace_start = rest.find('{').unwrap_or(rest.len());
+
+            let buttons_part = &rest[..brace_start];
+            let buttons: Vec<Vec<usize>> = buttons_part
+                .split('(')
+                .skip(1)
+                .filter_map(|s| s.find(')').map(|end| &s[..end]))
+                .filter(|s| !s.trim().is_empty())
+                .map(|s| parse_list(input, s))
+                .collect::<Result<_, _>>()?;
+
+            if brace_start == rest.len() {
+                return Err(InputError::malformed(
+                    input,
+                    &rest[brace_start..],
+                    "expected {joltage targets}",
+                ));
+            }
+            let Some(brace_end) = rest[brace_start..].find('}').map(|end| brace_start + end) else {
+                return Err(InputError::malformed(
+                    input,
+                    &rest[brace_start..],
+                    "unclosed {",
+                ));
+            };
+            let target_joltage = parse_list(input, &rest[brace_start + 1..brace_end])?;
+
+            // Part 2 relies on one joltage target per counter
+            let counters = bracket_end - bracket_start - 1;
+            if target_joltage.len() != counters {
+                return Err(InputError::malformed(
+                    input,
+                    &rest[brace_start..],
+                    format!("expected {} joltage targets", counters),
+                ));
+            }
+            if buttons.len() > MAX_BUTTONS {
+                return Err(InputError::malformed(
+                    input,
+                    buttons_part,
+                    format!("more than {} buttons", MAX_BUTTONS),
+                ));
+            }
+            if let Some(&index) = buttons.iter().flatten().find(|&&i| i >= counters) {
+                return Err(InputError::malformed(
+                    input,
+                    line,
+                    format!(
+                        "button wires light {} but there are only {}",
+                        index, counters
+                    ),
+                ));
+            }
+
+            Ok((target_lights, target_joltage, buttons))
+        })
+        .collect()
+}
+
+/// Find the minimum number of button presses to match the light pattern (Part 1)
+fn part1(data: &[ParsedData]) -> u64 {
+    data.iter()
+        .map(|(target, _, buttons)| {
+            // Convert button indices to bitmasks for efficient XOR operations
+            let button_masks: Vec<u64> = buttons
+                .iter()
+                .map(|b| b.iter().fold(0, |acc, &i| acc | (1 << i)))
+                .collect();
+
+            let n = button_masks.len();
+            // Brute force: check all subsets of buttons by increasing size
+            for size in 0..=n {
+                for mask in 0u64..(1 << n) {
+                    if mask.count_ones() as usize != size {
+                        continue;
+                    }
+
+                    let mut state = 0;
+                    for (i, &button) in button_masks.iter().enumerate() {
+                        if (mask >> i) & 1 != 0 {
+                            state ^= button;
+                        }
+                    }
+                    if state == *target {
+                        return size as u64;
+                    }
+                }
+            }
+            0
+        })
+        .sum()
+}
+
+/// Tries every value of the free variables up to their bounds, keeping the smallest
+/// total of presses among the non-negative integer solutions
+///
+/// `presses` is scratch space for the solutions, one entry per button.
+fn min_presses(
+    solutions: &Solutions,
+    bounds: &[i64],
+    values: &mut Vec<i64>,
+    presses: &mut [i128],
+    best: &mut u64,
+) {
+    if values.len() == bounds.len() {
+        if solutions.integer_solution_into(values, presses) && presses.iter().all(|&x| x >= 0) {
+            *best = (*best).min(presses.iter().sum::<i128>() as u64);
+        }
+        return;
+    }
+
+    for value in 0..=bounds[values.len()] {
+        values.push(value);
+        min_presses(solutions, bounds, values, presses, best);
+        values.pop();
+    }
+}
+
+/// Solves using linear algebra.
+///
+/// Treat the problem as a system of equations where it needs to find the right number of presses for each button.
+/// To solve this, use [Gaussian Elimination](https://en.wikipedia.org/wiki/Gaussian_elimination) to simplify the system.
+///
+/// Since there can be multiple valid solutions, then search through them to find the one that requires
+/// the minimum total number of button presses.
+fn part2(data: &[ParsedData]) -> u64 {
+    data.iter()
+        .map(|(_, targets, buttons)| {
+            if buttons.is_empty() {
+                return 0;
+            }
+
+            // One equation per counter, one variable per button
+            let matrix = Matrix::from_rows(
+                buttons.len(),
+                (0..targets.len()).map(|r| buttons.iter().map(move |b| b.contains(&r) as i64)),
+            );
+            let targets: Vec<Rational> = targets.iter().map(|&t| (t as i64).into()).collect();
+            let Some(solutions) = Solutions::of(&matrix, &targets) else {
+                return 0;
+            };
+
+            // A button cannot be pressed more than the smallest target it increments;
+            // parsing checks there is a target for every light a button wires
+            let bounds: Vec<i64> = solutions
+                .free_variables()
+                .iter()
+                .map(|&col| {
+                    buttons[col]
+                        .iter()
+                        .filter_map(|&row| targets.get(row))
+                        .map(|target| target.numerator() as i64)
+                        .min()
+                        .unwrap_or(0)
+                })
+                .collect();
+
+            let mut best = u64::MAX;
+            let mut presses = vec![0; buttons.len()];
+            min_presses(
+                &solutions,
+                &bounds,
+                &mut Vec::new(),
+                &mut presses,
+                &mut best,
+            );
+            if best == u64::MAX { 0 } else { best }
+        })
+        .sum()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Factory";
+    const SUMMARY: &'static [&'static str] = &[
+        "Minimum de pressions de boutons pour activer toutes les lumières (Brute-force)",
+        "Minimum de pressions pour atteindre le voltage cible (Élimination de Gauss)",
+    ];
+
+    type Input<'a> = Vec<ParsedData>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, InputError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Option<u64> {
+        Some(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_error(input: &str) -> String {
+        Day10::parse(input).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn test_malformed_delimiters() {
+        assert_eq!(
+            parse_error("] [#. (0) {1,2}"),
+            "line 1, column 1: expected [lights]"
+        );
+        assert_eq!(
+            parse_error("[#.] (0) } {3"),
+            "line 1, column 12: unclosed {"
+        );
+        assert_eq!(
+            parse_error("[#.] (0) (1)"),
+            "line 1, column 13: expected {joltage targets}"
+        );
+        assert_eq!(
+            parse_error("[#.] (0) (1) {3}"),
+            "line 1, column 14: expected 2 joltage targets"
+        );
+    }
+
+    #[test]
+    fn test_too_many_lights_or_buttons() {
+        let lights = format!("[{}] (0) {{}}", ".".repeat(65));
+        assert_eq!(
+            parse_error(&lights),
+            "line 1, column 2: more than 64 lights"
+        );
+        let buttons = format!("[#.] {}{{1,2}}", "(0) ".repeat(25));
+        assert_eq!(
+            parse_error(&buttons),
+            "line 1, column 5: more than 24 buttons"
+        );
+    }
+}
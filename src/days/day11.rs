@@ -0,0 +1,146 @@
+use crate::graph::{Graph, GraphBuilder, PathCountError};
+use crate::{InputError, Solution, non_empty_lines};
+
+/// Device whose paths are counted in part 1
+const YOU: &str = "you";
+/// Server rack where part 2 starts
+const SERVER: &str = "svr";
+/// Devices every path of part 2 must go through
+const CHECKPOINTS: [&str; 2] = ["dac", "fft"];
+/// Reactor output, where every path ends
+const OUT: &str = "out";
+
+/// Parse input into a graph: device -> devices its outputs are wired to
+fn parse_graph(input: &str) -> Result<Graph<'_>, InputError> {
+    let mut builder = GraphBuilder::new();
+    for line in non_empty_lines(input) {
+        let Some((from, rest)) = line.split_once(':') else {
+            return Err(InputError::malformed(
+                input,
+                line,
+                "expected device: outputs",
+            ));
+        };
+        let from = from.trim();
+        builder.node(from);
+        for to in rest.split_whitespace() {
+            builder.edge(from, to);
+        }
+    }
+    let graph = builder.build();
+
+    // Paths are only finite without cycles; those the puzzle cannot reach do not matter
+    if let Some(cycle) = [YOU, SERVER]
+        .into_iter()
+        .filter_map(|name| graph.node(name))
+        .find_map(|start| graph.find_cycle_from(start))
+    {
+        let names: Vec<&str> = cycle.iter().map(|&n| graph.name(n)).collect();
+        let line = non_empty_lines(input)
+            .find(|line| line.split(':').next().map(str::trim) == Some(names[0]))
+            .unwrap_or(input);
+        return Err(InputError::malformed(
+            input,
+            line,
+            format!(
+                "devices are wired in a cycle: {} -> {}",
+                names.join(" -> "),
+                names[0]
+            ),
+        ));
+    }
+    Ok(graph)
+}
+
+/// Count paths between two named devices, 0 if either is missing
+fn count_paths(graph: &Graph, from: &str, to: &str) -> Result<u64, PathCountError> {
+    match (graph.node(from), graph.node(to)) {
+        (Some(from), Some(to)) => graph.count_paths(from, to),
+        _ => Ok(0),
+    }
+}
+
+/// Count paths going through `legs` one after the other
+///
+/// Legs are only counted once the previous ones are known to be reachable, so that
+/// the cycles rejected by parsing are the only ones that could matter.
+fn count_legs(graph: &Graph, legs: &[(&str, &str)]) -> Result<u64, PathCountError> {
+    let mut total = 1u64;
+    for &(from, to) in legs {
+        let count = count_paths(graph, from, to)?;
+        if count == 0 {
+            return Ok(0);
+        }
+        total = total.checked_mul(count).ok_or(PathCountError::Overflow)?;
+    }
+    Ok(total)
+}
+
+/// Count distinct paths from "you" to "out" in a directed graph.
+fn part1(graph: &Graph) -> u64 {
+    count_legs(graph, &[(YOU, OUT)])
+        .unwrap_or_else(|e| panic!("cannot count paths from {} to {}: {}", YOU, OUT, e))
+}
+
+/// Count paths from "svr" to "out" that visit both "dac" and "fft"
+///
+/// Without cycles, such a path goes through the checkpoints in one order or the
+/// other, and each leg can be counted on its own.
+fn part2(graph: &Graph) -> u64 {
+    let [a, b] = CHECKPOINTS;
+    let through =
+        |first, second| count_legs(graph, &[(SERVER, first), (first, second), (second, OUT)]);
+    through(a, b)
+        .and_then(|ab| {
+            ab.checked_add(through(b, a)?)
+                .ok_or(PathCountError::Overflow)
+        })
+        .unwrap_or_else(|e| panic!("cannot count paths from {} to {}: {}", SERVER, OUT, e))
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Reactor";
+    const SUMMARY: &'static [&'static str] = &[
+        "Compter les chemins distincts de 'you' vers 'out' dans le graphe",
+        "Compter les chemins de 'svr' vers 'out' passant par 'dac' et 'fft'",
+    ];
+
+    type Input<'a> = Graph<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, InputError> {
+        parse_graph(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Option<u64> {
+        Some(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cycle() {
+        let err = Day11::parse("you: aaa\naaa: bbb\nbbb: aaa out").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: devices are wired in a cycle: aaa -> bbb -> aaa"
+        );
+    }
+
+    #[test]
+    fn test_unreachable_cycle() {
+        // The aaa <-> bbb loop cannot be reached from "you" nor "svr"
+        let graph = Day11::parse("you: ccc\nccc: out\naaa: bbb\nbbb: aaa out").unwrap();
+        assert_eq!(part1(&graph), 1);
+        assert_eq!(part2(&graph), 0);
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{InputError, Solution, input::parse_field, input::parse_fields, lines};
+
+/// (width, height, count of each shape)
+type Region = (u64, u64, Vec<u64>);
+
+/// (Area of each shape, regions to fill)
+type Farm = (Vec<u64>, Vec<Region>);
+
+/// Parses input into shape areas and regions (width, height, counts per shape).
+fn parse_input(input: &str) -> Result<Farm, InputError> {
+    let mut shape_areas = Vec::new();
+    let mut regions = Vec::new();
+    let lines: Vec<&str> = lines(input).collect();
+
+    for chunk in lines.split(|line| line.trim().is_empty()) {
+        if chunk.is_empty() {
+            continue;
+        }
+
+        // Check for shape definition (e.g., "0:")
+        if let Some(header) = chunk[0].trim().strip_suffix(':')
+            && header.parse::<usize>().is_ok()
+        {
+            let area: u64 = chunk[1..]
+                .iter()
+                .map(|line| line.chars().filter(|&c| c == '#').count() as u64)
+                .sum();
+            shape_areas.push(area);
+            continue;
+        }
+
+        // Parse regions in the chunk
+        for line in chunk {
+            let Some((dims, counts_str)) = line.split_once(':') else {
+                return Err(InputError::malformed(input, line, "expected WxH: counts"));
+            };
+            let [w, h] = parse_fields(input, dims, 'x')?;
+            let counts: Vec<u64> = counts_str
+                .split_whitespace()
+                .map(|s| parse_field(input, s))
+                .collect::<Result<_, _>>()?;
+            if counts.len() > shape_areas.len() {
+                return Err(InputError::malformed(
+                    input,
+                    counts_str,
+                    format!("only {} shapes are defined", shape_areas.len()),
+                ));
+            }
+            regions.push((w, h, counts));
+        }
+    }
+
+    Ok((shape_areas, regions))
+}
+
+/// Counts regions where the total area of presents fits in the region.
+fn part1((shape_areas, regions): &Farm) -> u64 {
+    let mut count = 0;
+
+    for (w, h, counts) in regions {
+        let region_area = w * h;
+        let mut present_area = 0;
+
+        for (i, &c) in counts.iter().enumerate() {
+            present_area += c * shape_areas[i];
+        }
+
+        if present_area <= region_area {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Christmas Tree Farm";
+    const SUMMARY: &'static [&'static str] =
+        &["Compter les régions où la surface totale des cadeaux tient dans la région"];
+
+    type Input<'a> = Farm;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, InputError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> u64 {
+        part1(input)
+    }
+}
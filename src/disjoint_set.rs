@@ -0,0 +1,136 @@
+//! Disjoint-set (union-find) over the elements `0..n`.
+
+/// Partition of `0..n` into components, merged with `union`.
+///
+/// Uses union by size and path compression, so that every operation runs in
+/// near-constant amortised time.
+#[derive(Clone, Debug)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    /// Size of each component, only meaningful at its root.
+    size: Vec<usize>,
+    count: usize,
+}
+
+impl DisjointSet {
+    /// `n` elements, each in its own component.
+    pub fn new(n: usize) -> Self {
+        DisjointSet {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            count: n,
+        }
+    }
+
+    /// Number of elements.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Number of components.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Representative of the component holding `x`.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        // Point every element of the path straight at the root
+        let mut x = x;
+        while self.parent[x] != root {
+            x = std::mem::replace(&mut self.parent[x], root);
+        }
+        root
+    }
+
+    /// Merges the components of `x` and `y`; returns false if they already were one.
+    pub fn union(&mut self, x: usize, y: usize) -> bool {
+        let (rx, ry) = (self.find(x), self.find(y));
+        if rx == ry {
+            return false;
+        }
+        let (small, big) = if self.size[rx] < self.size[ry] {
+            (rx, ry)
+        } else {
+            (ry, rx)
+        };
+        self.parent[small] = big;
+        self.size[big] += self.size[small];
+        self.count -= 1;
+        true
+    }
+
+    /// Whether `x` and `y` are in the same component.
+    pub fn same(&mut self, x: usize, y: usize) -> bool {
+        self.find(x) == self.find(y)
+    }
+
+    /// Size of the component holding `x`.
+    pub fn size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// Size of every component, in no particular order.
+    pub fn sizes(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.len())
+            .filter(|&x| self.parent[x] == x)
+            .map(|root| self.size[root])
+    }
+
+    /// Elements of every component, each in increasing order.
+    pub fn components(&mut self) -> impl Iterator<Item = Vec<usize>> + use<> {
+        let mut components: Vec<Vec<usize>> = vec![Vec::new(); self.len()];
+        for x in 0..self.len() {
+            let root = self.find(x);
+            components[root].push(x);
+        }
+        components.into_iter().filter(|c| !c.is_empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_union_find() {
+        let mut set = DisjointSet::new(6);
+        assert_eq!(set.count(), 6);
+        assert!(set.union(0, 1));
+        assert!(set.union(2, 1));
+        assert!(!set.union(0, 2));
+        assert!(set.union(4, 5));
+        assert!(set.same(0, 2) && !set.same(0, 3));
+        assert_eq!((set.count(), set.size(2), set.size(3)), (3, 3, 1));
+
+        let mut sizes: Vec<usize> = set.sizes().collect();
+        sizes.sort_unstable();
+        assert_eq!(sizes, [1, 2, 3]);
+        let mut components: Vec<Vec<usize>> = set.components().collect();
+        components.sort();
+        assert_eq!(components, [vec![0, 1, 2], vec![3], vec![4, 5]]);
+    }
+
+    #[test]
+    fn test_long_chain() {
+        // Deep enough that a recursive `find` would overflow the stack
+        let n = 1_000_000;
+        let mut set = DisjointSet::new(n);
+        for x in 1..n {
+            set.parent[x - 1] = x;
+        }
+        set.size[n - 1] = n;
+        set.count = 1;
+        assert_eq!(set.find(0), n - 1);
+        assert_eq!(set.parent[n / 2], n - 1);
+        assert_eq!(set.size(0), n);
+    }
+}
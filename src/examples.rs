@@ -0,0 +1,25 @@
+//! Tests of the puzzle examples, generated by `build.rs` from `inputs/examples`.
+//!
+//! Adding an example is a file drop: put it in `inputs/examples/dayXX-N.txt` and its
+//! expected answers in a `[dayXX-N]` table of `inputs/examples/answers.toml`.
+
+use crate::input::line_ending_variants;
+use crate::{day, lines};
+
+/// Checks one part of a day on an example, with every line ending variant.
+fn check(number: u8, part: u8, example: &str, expected: u64) {
+    let day = day(number).unwrap_or_else(|| panic!("day {:02} is not registered", number));
+    let example = lines(example).collect::<Vec<_>>().join("\n");
+    for input in line_ending_variants(&example) {
+        let parsed = day
+            .parse(&input)
+            .unwrap_or_else(|e| panic!("{:?}: {}", input, e));
+        let actual = match part {
+            1 => Some(parsed.part1()),
+            _ => parsed.part2(),
+        };
+        assert_eq!(actual, Some(expected), "part {} of {:?}", part, input);
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
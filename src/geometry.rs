@@ -0,0 +1,286 @@
+//! Integer points, axis-aligned rectangles and segments.
+
+use crate::InputError;
+use crate::input::parse_fields;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A point (or vector) of the plane.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// A point (or vector) of space.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point2 {
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point2 { x, y }
+    }
+
+    /// Parses an "x,y" line of `input`.
+    pub fn parse(input: &str, line: &str) -> Result<Self, InputError> {
+        let [x, y] = parse_fields(input, line, ',')?;
+        Ok(Point2 { x, y })
+    }
+
+    pub fn manhattan(self, other: Point2) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Square of the Euclidean distance, exact unlike `distance`.
+    pub fn squared_distance(self, other: Point2) -> u64 {
+        let (dx, dy) = (self.x.abs_diff(other.x), self.y.abs_diff(other.y));
+        dx * dx + dy * dy
+    }
+
+    pub fn distance(self, other: Point2) -> f64 {
+        (self.squared_distance(other) as f64).sqrt()
+    }
+}
+
+impl Point3 {
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Point3 { x, y, z }
+    }
+
+    /// Parses an "x,y,z" line of `input`.
+    pub fn parse(input: &str, line: &str) -> Result<Self, InputError> {
+        let [x, y, z] = parse_fields(input, line, ',')?;
+        Ok(Point3 { x, y, z })
+    }
+
+    pub fn manhattan(self, other: Point3) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    /// Square of the Euclidean distance, exact unlike `distance`.
+    pub fn squared_distance(self, other: Point3) -> u64 {
+        let (dx, dy, dz) = (
+            self.x.abs_diff(other.x),
+            self.y.abs_diff(other.y),
+            self.z.abs_diff(other.z),
+        );
+        dx * dx + dy * dy + dz * dz
+    }
+
+    pub fn distance(self, other: Point3) -> f64 {
+        (self.squared_distance(other) as f64).sqrt()
+    }
+}
+
+/// Component-wise operators shared by both point types.
+macro_rules! impl_ops {
+    ($point:ident { $($field:ident),+ }) => {
+        impl Add for $point {
+            type Output = $point;
+
+            fn add(self, other: $point) -> $point {
+                $point { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = $point;
+
+            fn sub(self, other: $point) -> $point {
+                $point { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = $point;
+
+            fn neg(self) -> $point {
+                $point { $($field: -self.$field),+ }
+            }
+        }
+
+        impl Mul<i64> for $point {
+            type Output = $point;
+
+            fn mul(self, k: i64) -> $point {
+                $point { $($field: self.$field * k),+ }
+            }
+        }
+    };
+}
+
+impl_ops!(Point2 { x, y });
+impl_ops!(Point3 { x, y, z });
+
+/// An axis-aligned rectangle of tiles, both corners included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rect {
+    min: Point2,
+    max: Point2,
+}
+
+impl Rect {
+    /// The rectangle with `a` and `b` at opposite corners, in any order.
+    pub fn from_corners(a: Point2, b: Point2) -> Self {
+        Rect {
+            min: Point2::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Point2::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    pub fn min(&self) -> Point2 {
+        self.min
+    }
+
+    pub fn max(&self) -> Point2 {
+        self.max
+    }
+
+    /// Number of columns of tiles.
+    pub fn width(&self) -> u64 {
+        self.max.x.abs_diff(self.min.x) + 1
+    }
+
+    /// Number of rows of tiles.
+    pub fn height(&self) -> u64 {
+        self.max.y.abs_diff(self.min.y) + 1
+    }
+
+    /// Number of tiles.
+    pub fn area(&self) -> u64 {
+        self.width() * self.height()
+    }
+
+    pub fn contains(&self, p: Point2) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x) && (self.min.y..=self.max.y).contains(&p.y)
+    }
+
+    /// Whether both rectangles share at least one tile.
+    pub fn intersects(&self, other: &Rect) -> bool {
+        self.min.x <= other.max.x
+            && other.min.x <= self.max.x
+            && self.min.y <= other.max.y
+            && other.min.y <= self.max.y
+    }
+
+    /// Whether `other`, seen as a continuous shape, enters the open interior of `self`.
+    ///
+    /// Touching the border does not count, so a segment along an edge of `self` or
+    /// ending on it does not cross it.
+    pub fn interior_overlaps(&self, other: &Rect) -> bool {
+        other.max.x > self.min.x
+            && other.min.x < self.max.x
+            && other.max.y > self.min.y
+            && other.min.y < self.max.y
+    }
+}
+
+/// A horizontal or vertical segment, both ends included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Segment {
+    /// Smallest rectangle holding the segment, one tile wide.
+    bounds: Rect,
+}
+
+impl Segment {
+    /// The segment from `a` to `b`, or `None` if it is neither horizontal nor vertical.
+    pub fn new(a: Point2, b: Point2) -> Option<Self> {
+        (a.x == b.x || a.y == b.y).then(|| Segment {
+            bounds: Rect::from_corners(a, b),
+        })
+    }
+
+    pub fn is_horizontal(&self) -> bool {
+        self.bounds.min.y == self.bounds.max.y
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        self.bounds.min.x == self.bounds.max.x
+    }
+
+    /// Number of tiles covered.
+    pub fn length(&self) -> u64 {
+        self.bounds.area()
+    }
+
+    pub fn bounds(&self) -> &Rect {
+        &self.bounds
+    }
+
+    pub fn contains(&self, p: Point2) -> bool {
+        self.bounds.contains(p)
+    }
+
+    /// Whether both segments share at least one tile.
+    pub fn intersects(&self, other: &Segment) -> bool {
+        // Axis-aligned segments are their own bounding boxes
+        self.bounds.intersects(&other.bounds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_points() {
+        let (a, b) = (Point2::new(1, 2), Point2::new(4, -2));
+        assert_eq!(a + b, Point2::new(5, 0));
+        assert_eq!(a - b, Point2::new(-3, 4));
+        assert_eq!(-a * 2, Point2::new(-2, -4));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.squared_distance(b), 25);
+        assert_eq!(a.distance(b), 5.0);
+
+        let p = Point3::new(1, 2, 3);
+        assert_eq!(p.manhattan(Point3::default()), 6);
+        assert_eq!(p.squared_distance(Point3::new(3, 5, 9)), 49);
+    }
+
+    #[test]
+    fn test_parse() {
+        let input = "7,1\n1, 2,3\n4,x";
+        let mut lines = input.lines();
+        assert_eq!(
+            Point2::parse(input, lines.next().unwrap()).unwrap(),
+            Point2::new(7, 1)
+        );
+        assert_eq!(
+            Point3::parse(input, lines.next().unwrap()).unwrap(),
+            Point3::new(1, 2, 3)
+        );
+        let err = Point2::parse(input, lines.next().unwrap()).unwrap_err();
+        assert_eq!(err.to_string(), "line 3, column 3: invalid number \"x\"");
+    }
+
+    #[test]
+    fn test_rect() {
+        let rect = Rect::from_corners(Point2::new(9, 5), Point2::new(2, 3));
+        assert_eq!(
+            (rect.min(), rect.max()),
+            (Point2::new(2, 3), Point2::new(9, 5))
+        );
+        assert_eq!((rect.width(), rect.height(), rect.area()), (8, 3, 24));
+        assert!(rect.contains(Point2::new(2, 5)) && !rect.contains(Point2::new(1, 4)));
+
+        let edge = Rect::from_corners(Point2::new(2, 3), Point2::new(9, 3));
+        assert!(rect.intersects(&edge) && !rect.interior_overlaps(&edge));
+        let across = Rect::from_corners(Point2::new(5, 0), Point2::new(5, 4));
+        assert!(rect.interior_overlaps(&across));
+    }
+
+    #[test]
+    fn test_segments() {
+        let h = Segment::new(Point2::new(0, 2), Point2::new(6, 2)).unwrap();
+        let v = Segment::new(Point2::new(3, 5), Point2::new(3, 0)).unwrap();
+        assert!(h.is_horizontal() && v.is_vertical());
+        assert_eq!((h.length(), v.length()), (7, 6));
+        assert!(h.intersects(&v) && v.contains(Point2::new(3, 2)));
+        let far = Segment::new(Point2::new(7, 0), Point2::new(7, 9)).unwrap();
+        assert!(!h.intersects(&far));
+        assert_eq!(Segment::new(Point2::new(0, 0), Point2::new(1, 1)), None);
+    }
+}
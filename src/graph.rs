@@ -0,0 +1,403 @@
+//! Directed graphs over named nodes.
+//!
+//! Node names are interned to dense ids `0..len`, and edges are stored in
+//! compressed sparse row form: the successors of every node are contiguous.
+
+use std::collections::HashMap;
+use std::fmt;
+
+/// Why the paths between two nodes cannot be counted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PathCountError {
+    /// A cycle lies on some path, so there are infinitely many of them.
+    Cycle,
+    /// There are more paths than fit in a `u64`.
+    Overflow,
+}
+
+impl fmt::Display for PathCountError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathCountError::Cycle => write!(f, "infinitely many paths through a cycle"),
+            PathCountError::Overflow => write!(f, "more paths than fit in a u64"),
+        }
+    }
+}
+
+impl std::error::Error for PathCountError {}
+
+/// Dense ids for names borrowed from the input.
+#[derive(Clone, Debug, Default)]
+pub struct Interner<'a> {
+    ids: HashMap<&'a str, usize>,
+    names: Vec<&'a str>,
+}
+
+impl<'a> Interner<'a> {
+    /// Id of `name`, assigning the next one if it is new.
+    pub fn intern(&mut self, name: &'a str) -> usize {
+        *self.ids.entry(name).or_insert_with(|| {
+            self.names.push(name);
+            self.names.len() - 1
+        })
+    }
+
+    pub fn get(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: usize) -> &'a str {
+        self.names[id]
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+/// Collects named edges, then builds a `Graph`.
+#[derive(Clone, Debug, Default)]
+pub struct GraphBuilder<'a> {
+    nodes: Interner<'a>,
+    edges: Vec<(usize, usize)>,
+}
+
+impl<'a> GraphBuilder<'a> {
+    pub fn new() -> Self {
+        GraphBuilder::default()
+    }
+
+    /// Adds a node without edges, returning its id.
+    pub fn node(&mut self, name: &'a str) -> usize {
+        self.nodes.intern(name)
+    }
+
+    pub fn edge(&mut self, from: &'a str, to: &'a str) {
+        let edge = (self.nodes.intern(from), self.nodes.intern(to));
+        self.edges.push(edge);
+    }
+
+    pub fn build(self) -> Graph<'a> {
+        Graph::from_edges(self.nodes, self.edges)
+    }
+}
+
+/// A directed graph, possibly with cycles and repeated edges.
+#[derive(Clone, Debug)]
+pub struct Graph<'a> {
+    nodes: Interner<'a>,
+    /// Successors of node `n` are `targets[offsets[n]..offsets[n + 1]]`.
+    offsets: Vec<usize>,
+    targets: Vec<usize>,
+}
+
+impl<'a> Graph<'a> {
+    fn from_edges(nodes: Interner<'a>, mut edges: Vec<(usize, usize)>) -> Self {
+        // A stable sort keeps the successors of each node in insertion order
+        edges.sort_by_key(|&(from, _)| from);
+        let mut offsets = vec![0; nodes.len() + 1];
+        for &(from, _) in &edges {
+            offsets[from + 1] += 1;
+        }
+        for n in 0..nodes.len() {
+            offsets[n + 1] += offsets[n];
+        }
+        Graph {
+            nodes,
+            offsets,
+            targets: edges.into_iter().map(|(_, to)| to).collect(),
+        }
+    }
+
+    /// Number of nodes.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Id of the node called `name`, if there is one.
+    pub fn node(&self, name: &str) -> Option<usize> {
+        self.nodes.get(name)
+    }
+
+    pub fn name(&self, node: usize) -> &'a str {
+        self.nodes.name(node)
+    }
+
+    pub fn successors(&self, node: usize) -> &[usize] {
+        &self.targets[self.offsets[node]..self.offsets[node + 1]]
+    }
+
+    /// The same nodes with every edge reversed.
+    pub fn reversed(&self) -> Graph<'a> {
+        let edges = (0..self.len())
+            .flat_map(|from| self.successors(from).iter().map(move |&to| (to, from)))
+            .collect();
+        Graph::from_edges(self.nodes.clone(), edges)
+    }
+
+    /// Nodes ordered so that every edge goes forward, or `None` if there is a cycle.
+    pub fn topological_order(&self) -> Option<Vec<usize>> {
+        let mut in_degree = vec![0; self.len()];
+        for &to in &self.targets {
+            in_degree[to] += 1;
+        }
+        let mut order: Vec<usize> = (0..self.len()).filter(|&n| in_degree[n] == 0).collect();
+        // `order` doubles as the queue of Kahn's algorithm
+        let mut next = 0;
+        while let Some(&node) = order.get(next) {
+            next += 1;
+            for &to in self.successors(node) {
+                in_degree[to] -= 1;
+                if in_degree[to] == 0 {
+                    order.push(to);
+                }
+            }
+        }
+        (order.len() == self.len()).then_some(order)
+    }
+
+    /// The nodes of some cycle, in order, or `None` if the graph is acyclic.
+    pub fn find_cycle(&self) -> Option<Vec<usize>> {
+        self.find_cycle_among(0..self.len())
+    }
+
+    /// The nodes of some cycle reachable from `from`, in order, or `None` if there
+    /// is none.
+    pub fn find_cycle_from(&self, from: usize) -> Option<Vec<usize>> {
+        self.find_cycle_among([from])
+    }
+
+    /// Depth-first search for a cycle, starting from each of `roots` in turn.
+    fn find_cycle_among(&self, roots: impl IntoIterator<Item = usize>) -> Option<Vec<usize>> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum State {
+            New,
+            OnStack,
+            Done,
+        }
+        let mut state = vec![State::New; self.len()];
+        for root in roots {
+            if state[root] != State::New {
+                continue;
+            }
+            // Iterative DFS: each entry is a node and the index of its next successor
+            let mut stack = vec![(root, 0)];
+            state[root] = State::OnStack;
+            while let Some((node, i)) = stack.last_mut() {
+                let Some(&to) = self.successors(*node).get(*i) else {
+                    state[*node] = State::Done;
+                    stack.pop();
+                    continue;
+                };
+                *i += 1;
+                match state[to] {
+                    State::New => {
+                        state[to] = State::OnStack;
+                        stack.push((to, 0));
+                    }
+                    State::OnStack => {
+                        let start = stack.iter().position(|&(n, _)| n == to).unwrap();
+                        return Some(stack[start..].iter().map(|&(n, _)| n).collect());
+                    }
+                    State::Done => {}
+                }
+            }
+        }
+        None
+    }
+
+    /// Which nodes can be reached from `from`, itself included.
+    pub fn reachable(&self, from: usize) -> Vec<bool> {
+        let mut seen = vec![false; self.len()];
+        seen[from] = true;
+        let mut stack = vec![from];
+        while let Some(node) = stack.pop() {
+            for &to in self.successors(node) {
+                if !seen[to] {
+                    seen[to] = true;
+                    stack.push(to);
+                }
+            }
+        }
+        seen
+    }
+
+    /// Number of distinct paths from `from` to every node.
+    ///
+    /// Fails if a cycle can be reached from `from`, since some counts would then be
+    /// infinite, or if a count overflows.
+    pub fn path_counts(&self, from: usize) -> Result<Vec<u64>, PathCountError> {
+        self.path_counts_within(from, &vec![true; self.len()])
+    }
+
+    /// Number of distinct paths from `from` to `to`.
+    pub fn count_paths(&self, from: usize, to: usize) -> Result<u64, PathCountError> {
+        // Cycles that cannot lead to `to` do not matter, so leave them out
+        let leads_to = self.reversed().reachable(to);
+        if !leads_to[from] {
+            return Ok(0);
+        }
+        Ok(self.path_counts_within(from, &leads_to)?[to])
+    }
+
+    /// Path counts from `from`, only going through the nodes in `within`.
+    fn path_counts_within(&self, from: usize, within: &[bool]) -> Result<Vec<u64>, PathCountError> {
+        let mut seen = vec![false; self.len()];
+        seen[from] = true;
+        let mut stack = vec![from];
+        let mut in_degree = vec![0; self.len()];
+        while let Some(node) = stack.pop() {
+            for &to in self.successors(node).iter().filter(|&&to| within[to]) {
+                in_degree[to] += 1;
+                if !seen[to] {
+                    seen[to] = true;
+                    stack.push(to);
+                }
+            }
+        }
+        if in_degree[from] > 0 {
+            return Err(PathCountError::Cycle);
+        }
+
+        // Kahn's algorithm, where `from` is the only source: counts are final once
+        // every path into a node has been followed
+        let mut counts = vec![0u64; self.len()];
+        counts[from] = 1;
+        let mut queue = vec![from];
+        let mut processed = 0;
+        while let Some(node) = queue.pop() {
+            processed += 1;
+            for &to in self.successors(node).iter().filter(|&&to| within[to]) {
+                counts[to] = counts[to]
+                    .checked_add(counts[node])
+                    .ok_or(PathCountError::Overflow)?;
+                in_degree[to] -= 1;
+                if in_degree[to] == 0 {
+                    queue.push(to);
+                }
+            }
+        }
+        if processed < seen.iter().filter(|&&s| s).count() {
+            return Err(PathCountError::Cycle);
+        }
+        Ok(counts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// a -> b -> d, a -> c -> d, a -> d, d -> e
+    fn diamond() -> Graph<'static> {
+        let mut builder = GraphBuilder::new();
+        for (from, to) in [
+            ("a", "b"),
+            ("a", "c"),
+            ("b", "d"),
+            ("c", "d"),
+            ("a", "d"),
+            ("d", "e"),
+        ] {
+            builder.edge(from, to);
+        }
+        builder.node("lonely");
+        builder.build()
+    }
+
+    #[test]
+    fn test_layout() {
+        let graph = diamond();
+        let a = graph.node("a").unwrap();
+        let names: Vec<&str> = graph.successors(a).iter().map(|&n| graph.name(n)).collect();
+        assert_eq!(names, ["b", "c", "d"]);
+        assert_eq!(graph.len(), 6);
+        assert_eq!(graph.node("z"), None);
+
+        let reversed = graph.reversed();
+        let d = graph.node("d").unwrap();
+        assert_eq!(reversed.successors(d).len(), 3);
+        assert_eq!(reversed.successors(a), []);
+    }
+
+    #[test]
+    fn test_order_and_cycles() {
+        let graph = diamond();
+        let order = graph.topological_order().unwrap();
+        let position = |name| order.iter().position(|&n| n == graph.node(name).unwrap());
+        assert!(position("a") < position("b") && position("b") < position("d"));
+        assert_eq!(graph.find_cycle(), None);
+
+        let mut builder = GraphBuilder::new();
+        for (from, to) in [("x", "y"), ("y", "z"), ("z", "y")] {
+            builder.edge(from, to);
+        }
+        let cyclic = builder.build();
+        assert_eq!(cyclic.topological_order(), None);
+        let cycle: Vec<&str> = cyclic
+            .find_cycle()
+            .unwrap()
+            .iter()
+            .map(|&n| cyclic.name(n))
+            .collect();
+        assert_eq!(cycle, ["y", "z"]);
+        assert_eq!(cyclic.count_paths(0, 1), Err(PathCountError::Cycle));
+        assert_eq!(cyclic.path_counts(0), Err(PathCountError::Cycle));
+
+        // The x <-> y cycle cannot be reached from "w"
+        let mut builder = GraphBuilder::new();
+        for (from, to) in [("w", "v"), ("x", "y"), ("y", "x")] {
+            builder.edge(from, to);
+        }
+        let graph = builder.build();
+        let id = |name| graph.node(name).unwrap();
+        assert_eq!(graph.find_cycle_from(id("w")), None);
+        assert_eq!(graph.find_cycle_from(id("y")).map(|c| c.len()), Some(2));
+        assert_eq!(graph.count_paths(id("w"), id("v")), Ok(1));
+    }
+
+    #[test]
+    fn test_paths() {
+        let graph = diamond();
+        let id = |name| graph.node(name).unwrap();
+        assert_eq!(graph.count_paths(id("a"), id("e")), Ok(3));
+        assert_eq!(graph.count_paths(id("b"), id("e")), Ok(1));
+        assert_eq!(graph.count_paths(id("e"), id("a")), Ok(0));
+        assert_eq!(graph.count_paths(id("a"), id("a")), Ok(1));
+        assert_eq!(graph.path_counts(id("a")).unwrap()[id("e")], 3);
+
+        let reachable = graph.reachable(id("b"));
+        assert!(reachable[id("e")] && !reachable[id("c")] && !reachable[id("lonely")]);
+    }
+
+    #[test]
+    fn test_overflow() {
+        // A chain of 64 diamonds has 2^64 paths from end to end
+        let names: Vec<String> = (0..=64).map(|i| i.to_string()).collect();
+        let sides: Vec<[String; 2]> = (0..64)
+            .map(|i| [format!("{}a", i), format!("{}b", i)])
+            .collect();
+        let mut builder = GraphBuilder::new();
+        for i in 0..64 {
+            for side in &sides[i] {
+                builder.edge(&names[i], side);
+                builder.edge(side, &names[i + 1]);
+            }
+        }
+        let graph = builder.build();
+        let id = |name: &str| graph.node(name).unwrap();
+        assert_eq!(graph.count_paths(id("0"), id("63")), Ok(1 << 63));
+        assert_eq!(
+            graph.count_paths(id("0"), id("64")),
+            Err(PathCountError::Overflow)
+        );
+    }
+}
@@ -0,0 +1,298 @@
+//! Rectangular grids of cells, as found in most puzzle inputs.
+
+use crate::{InputError, lines};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// Position of a cell, as (row, column).
+pub type Pos = (usize, usize);
+
+/// Offsets of the 4 orthogonal neighbours.
+pub const NEIGHBORS4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// Offsets of the 8 neighbours, diagonals included.
+pub const NEIGHBORS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A rectangular grid stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// A `width` × `height` grid filled with `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Parses one cell per character of each line of `input`.
+    ///
+    /// `cell` receives each character as a slice of `input`, so that it can report
+    /// where a bad cell is. Lines shorter than the longest one are padded with `pad`,
+    /// or rejected when there is no padding.
+    pub fn parse(
+        input: &str,
+        pad: Option<T>,
+        mut cell: impl FnMut(&str) -> Result<T, InputError>,
+    ) -> Result<Self, InputError>
+    where
+        T: Clone,
+    {
+        let width = match pad {
+            Some(_) => lines(input).map(|line| line.chars().count()).max(),
+            None => lines(input).next().map(|line| line.chars().count()),
+        }
+        .unwrap_or(0);
+
+        let mut cells = Vec::new();
+        let mut height = 0;
+        for line in lines(input) {
+            let row_start = cells.len();
+            for (i, c) in line.char_indices() {
+                if cells.len() - row_start == width {
+                    return Err(InputError::malformed(
+                        input,
+                        &line[i..],
+                        format!("expected {} cells, found {}", width, line.chars().count()),
+                    ));
+                }
+                cells.push(cell(&line[i..i + c.len_utf8()])?);
+            }
+            let missing = width - (cells.len() - row_start);
+            match &pad {
+                Some(pad) => cells.extend(std::iter::repeat_n(pad.clone(), missing)),
+                None if missing > 0 => {
+                    return Err(InputError::malformed(
+                        input,
+                        &line[line.len()..],
+                        format!("expected {} cells, found {}", width, width - missing),
+                    ));
+                }
+                None => {}
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The cell at `pos`, if it is inside the grid.
+    pub fn get(&self, (row, col): Pos) -> Option<&T> {
+        (row < self.height && col < self.width).then(|| &self.cells[row * self.width + col])
+    }
+
+    pub fn get_mut(&mut self, (row, col): Pos) -> Option<&mut T> {
+        (row < self.height && col < self.width).then(|| &mut self.cells[row * self.width + col])
+    }
+
+    /// The cell at (`row`, `col`), wrapping around the edges in both directions, or
+    /// `None` if the grid is empty.
+    pub fn get_wrapping(&self, row: isize, col: isize) -> Option<&T> {
+        if self.cells.is_empty() {
+            return None;
+        }
+        let row = row.rem_euclid(self.height as isize) as usize;
+        let col = col.rem_euclid(self.width as isize) as usize;
+        Some(&self[(row, col)])
+    }
+
+    /// `pos` moved by `offset`, if the result is inside the grid.
+    pub fn offset(&self, (row, col): Pos, (dr, dc): (isize, isize)) -> Option<Pos> {
+        let row = row.checked_add_signed(dr).filter(|&r| r < self.height)?;
+        let col = col.checked_add_signed(dc).filter(|&c| c < self.width)?;
+        Some((row, col))
+    }
+
+    /// Orthogonal neighbours of `pos` inside the grid.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBORS4.iter().filter_map(move |&d| self.offset(pos, d))
+    }
+
+    /// Neighbours of `pos` inside the grid, diagonals included.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBORS8.iter().filter_map(move |&d| self.offset(pos, d))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    /// Every row in order, `height` of them even when they are empty.
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(col < self.width, "column {} out of {}", col, self.width);
+        (0..self.height).map(move |row| &self.cells[row * self.width + col])
+    }
+
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(move |col| self.column(col))
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The grid with rows and columns swapped.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: self.columns().flatten().cloned().collect(),
+            width: self.height,
+            height: self.width,
+        }
+    }
+
+    /// A grid of the same size with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} out of a {}x{} grid", pos, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} out of a {}x{} grid", pos, width, height))
+    }
+}
+
+/// One line per row, for grids of characters or ASCII bytes.
+impl<T: Copy + Into<char>> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            let line: String = row.iter().map(|&c| c.into()).collect();
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bytes(input: &str, pad: Option<u8>) -> Result<Grid<u8>, InputError> {
+        Grid::parse(input, pad, |cell| Ok(cell.as_bytes()[0]))
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = bytes("ab\nc\ndef\n", Some(b' ')).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid.to_string(), "ab \nc  \ndef\n");
+        assert_eq!(grid.row(1), b"c  ");
+
+        let err = bytes("ab\nc\n", None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 2: expected 2 cells, found 1"
+        );
+        let err = bytes("ab\ncde\n", None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: expected 2 cells, found 3"
+        );
+
+        let input = "..\n.x";
+        let err = Grid::parse(input, None, |cell| match cell {
+            "." => Ok(false),
+            _ => Err(InputError::malformed(input, cell, "expected .")),
+        })
+        .unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 2: expected .");
+    }
+
+    #[test]
+    fn test_indexing() {
+        let grid = bytes("abc\ndef", None).unwrap();
+        assert_eq!(grid[(1, 2)], b'f');
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get_wrapping(-1, 3), Some(&b'd'));
+        assert_eq!(bytes("", None).unwrap().get_wrapping(0, 0), None);
+        assert_eq!(grid.offset((0, 0), (-1, 0)), None);
+        assert_eq!(grid.offset((0, 0), (1, 1)), Some((1, 1)));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::new(3, 3, 0u8);
+        assert_eq!(grid.neighbors4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            [(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbors8((2, 2)).count(), 3);
+    }
+
+    #[test]
+    fn test_rows_columns() {
+        let grid = bytes("abc\ndef", None).unwrap();
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [b"abc", b"def"]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), b"be");
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.iter().nth(4), Some(((1, 1), &b'e')));
+        assert_eq!(grid.map(|&b| b - b'a').row(1), [3, 4, 5]);
+
+        let narrow = Grid::new(0, 2, b'.');
+        assert_eq!(narrow.rows().count(), narrow.height());
+        assert_eq!(narrow.to_string(), "\n\n");
+        assert_eq!(Grid::new(3, 0, b'.').columns().count(), 3);
+    }
+}
@@ -0,0 +1,388 @@
+//! Loading puzzle inputs and reporting what is wrong with them.
+
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable overriding the directory holding `dayXX.txt` files.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Name used in diagnostics when the input comes from stdin.
+const STDIN_NAME: &str = "<stdin>";
+
+/// Everything that can go wrong between the input file and a parsed puzzle.
+#[derive(Debug)]
+pub enum InputError {
+    /// The input file does not exist.
+    Missing { path: PathBuf },
+    /// The input file exists but could not be read.
+    Io { path: PathBuf, source: io::Error },
+    /// The input file is not valid UTF-8 past `offset` bytes.
+    NotUtf8 { path: PathBuf, offset: usize },
+    /// The input file only contains whitespace.
+    Empty { path: PathBuf },
+    /// A line does not follow the puzzle format (1-based line and column).
+    Malformed {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+}
+
+impl InputError {
+    /// Builds a `Malformed` error pointing at `at`, which must be a slice of `input`.
+    pub fn malformed(input: &str, at: &str, message: impl Into<String>) -> Self {
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset <= input.len())
+            .expect("`at` must be a slice of `input`");
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        InputError::Malformed {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            message: message.into(),
+        }
+    }
+
+    /// Builds a `Malformed` error pointing just past the end of `input`.
+    pub fn at_end(input: &str, message: impl Into<String>) -> Self {
+        Self::malformed(input, &input[input.len()..], message)
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Missing { path } => write!(f, "{} not found", path.display()),
+            InputError::Io { path, source } => {
+                write!(f, "failed to read {}: {}", path.display(), source)
+            }
+            InputError::NotUtf8 { path, offset } => {
+                write!(f, "{} is not valid UTF-8 (byte {})", path.display(), offset)
+            }
+            InputError::Empty { path } => write!(f, "{} is empty", path.display()),
+            InputError::Malformed {
+                line,
+                column,
+                message,
+            } => write!(f, "line {}, column {}: {}", line, column, message),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// The input without its trailing blank lines and final line terminator.
+fn content(input: &str) -> &str {
+    let Some(last) = input.rfind(|c: char| !c.is_whitespace()) else {
+        return "";
+    };
+    let end = input[last..]
+        .find(['\r', '\n'])
+        .map_or(input.len(), |i| last + i);
+    &input[..end]
+}
+
+/// Lines of the input without their terminator (`\n` or `\r\n`), borrowed from it.
+///
+/// Trailing blank lines are dropped, so that an input behaves the same whether or
+/// not it ends with a newline.
+pub fn lines(input: &str) -> std::str::Lines<'_> {
+    content(input).lines()
+}
+
+/// Lines of the input that are not blank.
+pub fn non_empty_lines(input: &str) -> impl Iterator<Item = &str> {
+    lines(input).filter(|line| !line.trim().is_empty())
+}
+
+/// Lines of a byte input without their terminator, for byte-oriented grids.
+///
+/// Line endings and trailing blank lines are handled like [`lines`].
+pub fn byte_lines(input: &[u8]) -> impl Iterator<Item = &[u8]> {
+    let end = input
+        .iter()
+        .rposition(|b| !b.is_ascii_whitespace())
+        .map_or(0, |last| {
+            input[last..]
+                .iter()
+                .position(|&b| b == b'\r' || b == b'\n')
+                .map_or(input.len(), |i| last + i)
+        });
+    input[..end].split_inclusive(|&b| b == b'\n').map(|line| {
+        let line = line.strip_suffix(b"\n").unwrap_or(line);
+        line.strip_suffix(b"\r").unwrap_or(line)
+    })
+}
+
+/// Fields of a line separated by `separator`, with surrounding whitespace trimmed.
+pub fn fields(line: &str, separator: char) -> impl Iterator<Item = &str> {
+    line.split(separator).map(str::trim)
+}
+
+/// Parses `field` (a slice of `input`), reporting its position on failure.
+pub fn parse_field<T: std::str::FromStr>(input: &str, field: &str) -> Result<T, InputError> {
+    field
+        .parse()
+        .map_err(|_| InputError::malformed(input, field, format!("invalid number {:?}", field)))
+}
+
+/// Parses a line made of exactly `N` fields separated by `separator` ("3-5", "7,1").
+pub fn parse_fields<T: std::str::FromStr, const N: usize>(
+    input: &str,
+    line: &str,
+    separator: char,
+) -> Result<[T; N], InputError> {
+    let fields: Vec<&str> = fields(line, separator).collect();
+    if fields.len() != N {
+        return Err(InputError::malformed(
+            input,
+            line,
+            format!("expected {} fields separated by {:?}", N, separator),
+        ));
+    }
+    let values: Vec<T> = fields
+        .iter()
+        .map(|field| parse_field(input, field))
+        .collect::<Result<_, _>>()?;
+    Ok(values
+        .try_into()
+        .unwrap_or_else(|_| unreachable!("length checked above")))
+}
+
+/// Directory holding the input files: `$AOC_INPUT_DIR`, or `inputs/` by default.
+pub fn input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("inputs"))
+}
+
+/// Path of the input file for a given day.
+pub fn input_path(day: u8) -> PathBuf {
+    input_dir().join(format!("day{:02}.txt", day))
+}
+
+/// Where a day reads its puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `dayXX.txt` in the input directory.
+    Day(u8),
+    /// An explicit file, given with `--input <path>`.
+    File(PathBuf),
+    /// Standard input, given with `--input -` or `-`.
+    Stdin,
+}
+
+impl InputSource {
+    /// Resolves the input source from command-line arguments (program name excluded).
+    pub fn from_args<I>(day: u8, args: I) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut source = InputSource::Day(day);
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let value = match arg.as_str() {
+                "--input" | "-i" => args
+                    .next()
+                    .ok_or_else(|| format!("{} expects a path or -", arg))?,
+                "-" => arg,
+                _ => match arg.strip_prefix("--input=") {
+                    Some(value) => value.to_string(),
+                    None => return Err(format!("unexpected argument {:?}", arg)),
+                },
+            };
+            source = if value == "-" {
+                InputSource::Stdin
+            } else {
+                InputSource::File(PathBuf::from(value))
+            };
+        }
+        Ok(source)
+    }
+
+    /// Reads the whole input, rejecting missing, non-UTF-8 and empty inputs.
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            InputSource::Day(day) => read_file(&input_path(*day)),
+            InputSource::File(path) => read_file(path),
+            InputSource::Stdin => {
+                let mut bytes = Vec::new();
+                io::stdin()
+                    .read_to_end(&mut bytes)
+                    .map_err(|source| InputError::Io {
+                        path: PathBuf::from(STDIN_NAME),
+                        source,
+                    })?;
+                decode(Path::new(STDIN_NAME), bytes)
+            }
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Day(day) => write!(f, "{}", input_path(*day).display()),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => f.write_str(STDIN_NAME),
+        }
+    }
+}
+
+/// Reads an input file, rejecting missing, non-UTF-8 and empty files.
+pub fn read_file(path: &Path) -> Result<String, InputError> {
+    let bytes = fs::read(path).map_err(|source| match source.kind() {
+        io::ErrorKind::NotFound => InputError::Missing {
+            path: path.to_path_buf(),
+        },
+        _ => InputError::Io {
+            path: path.to_path_buf(),
+            source,
+        },
+    })?;
+    decode(path, bytes)
+}
+
+/// Checks that raw input bytes are non-empty UTF-8 text.
+fn decode(path: &Path, bytes: Vec<u8>) -> Result<String, InputError> {
+    let text = String::from_utf8(bytes).map_err(|e| InputError::NotUtf8 {
+        path: path.to_path_buf(),
+        offset: e.utf8_error().valid_up_to(),
+    })?;
+
+    if text.trim().is_empty() {
+        return Err(InputError::Empty {
+            path: path.to_path_buf(),
+        });
+    }
+    Ok(text)
+}
+
+/// Reads the input file for a given day.
+pub fn read_input(day: u8) -> Result<String, InputError> {
+    InputSource::Day(day).read()
+}
+
+/// Variants of an example with a trailing newline, trailing blank lines and CRLF endings.
+#[cfg(test)]
+pub(crate) fn line_ending_variants(example: &str) -> [String; 4] {
+    let crlf = example.replace('\n', "\r\n");
+    [
+        format!("{}\n", example),
+        format!("{}\n\n\n", example),
+        format!("{}\r\n", crlf),
+        format!("{}\r\n\r\n", crlf),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_malformed_position() {
+        let input = "1-2\n3-x\n";
+        let at = &input[6..7];
+        match InputError::malformed(input, at, "bad") {
+            InputError::Malformed { line, column, .. } => assert_eq!((line, column), (2, 3)),
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_borrowing_iterators() {
+        let input = "12,3\n\n 4 , 5\r\n";
+        assert_eq!(lines(input).collect::<Vec<_>>(), ["12,3", "", " 4 , 5"]);
+        assert_eq!(non_empty_lines(input).count(), 2);
+        assert_eq!(fields(" 4 , 5", ',').collect::<Vec<_>>(), ["4", "5"]);
+        assert_eq!(
+            byte_lines(input.as_bytes()).collect::<Vec<_>>(),
+            [&b"12,3"[..], b"", b" 4 , 5"]
+        );
+        assert_eq!(byte_lines(b"").count(), 0);
+    }
+
+    #[test]
+    fn test_line_endings() {
+        for input in line_ending_variants("a \n\nb") {
+            assert_eq!(
+                lines(&input).collect::<Vec<_>>(),
+                ["a ", "", "b"],
+                "{:?}",
+                input
+            );
+            assert_eq!(
+                byte_lines(input.as_bytes()).collect::<Vec<_>>(),
+                [&b"a "[..], b"", b"b"],
+                "{:?}",
+                input
+            );
+        }
+        assert_eq!(lines(" \n\r\n").count(), 0);
+    }
+
+    #[test]
+    fn test_read_file_errors() {
+        let dir = std::env::temp_dir().join(format!("aoc-input-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let missing = dir.join("missing.txt");
+        assert!(matches!(
+            read_file(&missing),
+            Err(InputError::Missing { .. })
+        ));
+
+        let empty = dir.join("empty.txt");
+        fs::write(&empty, " \n").unwrap();
+        assert!(matches!(read_file(&empty), Err(InputError::Empty { .. })));
+
+        let binary = dir.join("binary.txt");
+        fs::write(&binary, b"12\xff").unwrap();
+        assert!(matches!(
+            read_file(&binary),
+            Err(InputError::NotUtf8 { offset: 2, .. })
+        ));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_source_from_args() {
+        let args = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(
+            InputSource::from_args(3, args(&[])),
+            Ok(InputSource::Day(3))
+        );
+        assert_eq!(
+            InputSource::from_args(3, args(&["--input", "other.txt"])),
+            Ok(InputSource::File(PathBuf::from("other.txt")))
+        );
+        assert_eq!(
+            InputSource::from_args(3, args(&["--input=other.txt"])),
+            Ok(InputSource::File(PathBuf::from("other.txt")))
+        );
+        assert_eq!(
+            InputSource::from_args(3, args(&["-"])),
+            Ok(InputSource::Stdin)
+        );
+        assert_eq!(
+            InputSource::from_args(3, args(&["--input", "-"])),
+            Ok(InputSource::Stdin)
+        );
+        assert!(InputSource::from_args(3, args(&["--input"])).is_err());
+        assert!(InputSource::from_args(3, args(&["--verbose"])).is_err());
+    }
+}
@@ -0,0 +1,207 @@
+//! Sets of `u64` stored as sorted, disjoint inclusive ranges.
+
+use std::ops::RangeInclusive;
+
+/// A set of `u64` values, stored as sorted inclusive ranges that neither overlap nor
+/// touch, so that each value has a single representation.
+///
+/// Ranges may end at `u64::MAX`: the set never computes `end + 1` without checking,
+/// and its size is a `u128` since the full domain holds 2^64 values.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    ranges: Vec<(u64, u64)>,
+}
+
+/// Whether a range ending at `end` touches or overlaps a later range starting at `start`.
+fn reaches(end: u64, start: u64) -> bool {
+    end.checked_add(1).is_none_or(|next| start <= next)
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        IntervalSet::default()
+    }
+
+    /// Every `u64` value.
+    pub fn full() -> Self {
+        IntervalSet {
+            ranges: vec![(0, u64::MAX)],
+        }
+    }
+
+    /// Adds every value of `range`; an empty range adds nothing.
+    pub fn insert(&mut self, range: RangeInclusive<u64>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        // Ranges merging with the new one are those from `lo` (inclusive) to `hi`
+        let lo = self.ranges.partition_point(|&(_, e)| !reaches(e, start));
+        let hi = self.ranges.partition_point(|&(s, _)| reaches(end, s));
+        let merged = match self.ranges[lo..hi] {
+            [] => (start, end),
+            [(first, _), ..] => (first.min(start), self.ranges[hi - 1].1.max(end)),
+        };
+        self.ranges.splice(lo..hi, [merged]);
+    }
+
+    /// Removes every value of `range`.
+    pub fn remove(&mut self, range: RangeInclusive<u64>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        // Ranges overlapping the removed one are those from `lo` (inclusive) to `hi`
+        let lo = self.ranges.partition_point(|&(_, e)| e < start);
+        let hi = self.ranges.partition_point(|&(s, _)| s <= end);
+        if lo == hi {
+            return;
+        }
+        let (first, last) = (self.ranges[lo].0, self.ranges[hi - 1].1);
+        let mut kept = Vec::with_capacity(2);
+        if first < start {
+            kept.push((first, start - 1));
+        }
+        if last > end {
+            kept.push((end + 1, last));
+        }
+        self.ranges.splice(lo..hi, kept);
+    }
+
+    pub fn contains(&self, value: u64) -> bool {
+        let i = self.ranges.partition_point(|&(s, _)| s <= value);
+        i > 0 && value <= self.ranges[i - 1].1
+    }
+
+    /// Number of values in the set.
+    pub fn len(&self) -> u128 {
+        self.ranges.iter().map(|&(s, e)| (e - s) as u128 + 1).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The ranges of the set, in increasing order.
+    pub fn ranges(&self) -> impl DoubleEndedIterator<Item = RangeInclusive<u64>> + '_ {
+        self.ranges.iter().map(|&(s, e)| s..=e)
+    }
+
+    /// The values of the set, in increasing order.
+    pub fn values(&self) -> impl Iterator<Item = u64> + '_ {
+        self.ranges().flatten()
+    }
+
+    /// Values in either set.
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut union = self.clone();
+        union.extend(other.ranges());
+        union
+    }
+
+    /// Values in both sets.
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ranges = Vec::new();
+        let (mut a, mut b) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+        while let (Some(&&(s1, e1)), Some(&&(s2, e2))) = (a.peek(), b.peek()) {
+            let (start, end) = (s1.max(s2), e1.min(e2));
+            if start <= end {
+                ranges.push((start, end));
+            }
+            // Drop whichever range ends first, the other may overlap the next one
+            if e1 < e2 {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// Values in `self` but not in `other`.
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut difference = self.clone();
+        for range in other.ranges() {
+            difference.remove(range);
+        }
+        difference
+    }
+}
+
+impl Extend<RangeInclusive<u64>> for IntervalSet {
+    fn extend<I: IntoIterator<Item = RangeInclusive<u64>>>(&mut self, ranges: I) {
+        for range in ranges {
+            self.insert(range);
+        }
+    }
+}
+
+impl FromIterator<RangeInclusive<u64>> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<u64>>>(ranges: I) -> Self {
+        let mut set = IntervalSet::new();
+        set.extend(ranges);
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[(u64, u64)]) -> IntervalSet {
+        ranges.iter().map(|&(s, e)| s..=e).collect()
+    }
+
+    #[test]
+    fn test_insert_merges() {
+        let mut ranges = set(&[(10, 14), (3, 5), (16, 20), (12, 18)]);
+        assert_eq!(ranges, set(&[(3, 5), (10, 20)]));
+        ranges.insert(6..=9);
+        assert_eq!(ranges.ranges().collect::<Vec<_>>(), [3..=20]);
+        ranges.insert(RangeInclusive::new(30, 29));
+        assert_eq!(ranges.len(), 18);
+        assert!(ranges.contains(3) && ranges.contains(20));
+        assert!(!ranges.contains(2) && !ranges.contains(21));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut ranges = set(&[(1, 10), (20, 30)]);
+        ranges.remove(5..=22);
+        assert_eq!(ranges, set(&[(1, 4), (23, 30)]));
+        ranges.remove(1..=4);
+        ranges.remove(40..=50);
+        assert_eq!(ranges, set(&[(23, 30)]));
+        assert_eq!(ranges.values().take(3).collect::<Vec<_>>(), [23, 24, 25]);
+    }
+
+    #[test]
+    fn test_full_domain() {
+        let mut full = IntervalSet::full();
+        assert_eq!(full.len(), 1 << 64);
+        full.insert(u64::MAX..=u64::MAX);
+        assert_eq!(full, IntervalSet::full());
+
+        let mut top = set(&[(u64::MAX - 1, u64::MAX), (0, 0)]);
+        top.insert(u64::MAX - 5..=u64::MAX - 2);
+        assert_eq!(top, set(&[(0, 0), (u64::MAX - 5, u64::MAX)]));
+        top.remove(u64::MAX..=u64::MAX);
+        assert_eq!(top.ranges().last(), Some(u64::MAX - 5..=u64::MAX - 1));
+
+        let rest = IntervalSet::full().difference(&set(&[(0, 0), (u64::MAX, u64::MAX)]));
+        assert_eq!(rest, set(&[(1, u64::MAX - 1)]));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[(1, 5), (10, 15)]);
+        let b = set(&[(4, 11), (15, 20)]);
+        assert_eq!(a.union(&b), set(&[(1, 20)]));
+        assert_eq!(a.intersection(&b), set(&[(4, 5), (10, 11), (15, 15)]));
+        assert_eq!(a.difference(&b), set(&[(1, 3), (12, 14)]));
+        assert!(a.intersection(&IntervalSet::new()).is_empty());
+    }
+}
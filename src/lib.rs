@@ -1,17 +1,169 @@
 //! Utility functions for Advent of Code 2025
 
-use std::fs;
+pub mod answers;
+pub mod days;
+pub mod disjoint_set;
+#[cfg(test)]
+mod examples;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod interval_set;
+pub mod linalg;
 
-/// Reads the input file for a given day.
-fn read_input(day: u8) -> String {
-    let path = format!("inputs/day{:02}.txt", day);
-    fs::read_to_string(&path).unwrap_or_else(|_| panic!("Failed to read {}", path))
+pub use disjoint_set::DisjointSet;
+pub use geometry::{Point2, Point3, Rect, Segment};
+pub use grid::Grid;
+pub use input::{InputError, InputSource, byte_lines, fields, lines, non_empty_lines, read_input};
+pub use interval_set::IntervalSet;
+
+use std::env;
+use std::process::ExitCode;
+use std::time::Instant;
+
+/// A solution for one day of the calendar.
+///
+/// The raw input is parsed once, then both parts run on the parsed representation.
+pub trait Solution {
+    /// Day of the calendar, from 1 to 25 like `runner scaffold` accepts.
+    const DAY: u8;
+    /// Puzzle title, as shown on the calendar.
+    const TITLE: &'static str;
+    /// One-line description of each part, printed in the summary.
+    const SUMMARY: &'static [&'static str];
+
+    /// Parsed representation shared by both parts, which may borrow from the raw input.
+    type Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, InputError>;
+
+    fn part1(input: &Self::Input<'_>) -> u64;
+
+    /// The last day only has one puzzle, so part 2 is optional.
+    fn part2(_input: &Self::Input<'_>) -> Option<u64> {
+        None
+    }
+}
+
+/// Parsed input of a day, with its concrete `Solution` type erased.
+pub trait Parsed {
+    fn part1(&self) -> u64;
+    fn part2(&self) -> Option<u64>;
+}
+
+struct ParsedInput<'a, S: Solution>(S::Input<'a>);
+
+impl<S: Solution> Parsed for ParsedInput<'_, S> {
+    fn part1(&self) -> u64 {
+        S::part1(&self.0)
+    }
+
+    fn part2(&self) -> Option<u64> {
+        S::part2(&self.0)
+    }
+}
+
+/// Registry entry for a day, usable without knowing its `Solution` type.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub number: u8,
+    pub title: &'static str,
+    pub summary: &'static [&'static str],
+    parse: for<'a> fn(&'a str) -> Result<Box<dyn Parsed + 'a>, InputError>,
 }
 
-/// Reads the input file and splits by a separator.
-pub fn read_split(day: u8, separator: &str) -> Vec<String> {
-    read_input(day)
-        .split(separator)
-        .map(String::from)
-        .collect()
+impl Day {
+    pub const fn of<S: Solution + 'static>() -> Self {
+        Day {
+            number: S::DAY,
+            title: S::TITLE,
+            summary: S::SUMMARY,
+            parse: |input| Ok(Box::new(ParsedInput::<S>(S::parse(input)?))),
+        }
+    }
+
+    /// Parses the raw input, returning a handle to run both parts.
+    pub fn parse<'a>(&self, input: &'a str) -> Result<Box<dyn Parsed + 'a>, InputError> {
+        (self.parse)(input)
+    }
+}
+
+/// Every implemented day, in calendar order.
+pub const DAYS: &[Day] = &[
+    Day::of::<days::day01::Day01>(),
+    Day::of::<days::day02::Day02>(),
+    Day::of::<days::day03::Day03>(),
+    Day::of::<days::day04::Day04>(),
+    Day::of::<days::day05::Day05>(),
+    Day::of::<days::day06::Day06>(),
+    Day::of::<days::day07::Day07>(),
+    Day::of::<days::day08::Day08>(),
+    Day::of::<days::day09::Day09>(),
+    Day::of::<days::day10::Day10>(),
+    Day::of::<days::day11::Day11>(),
+    Day::of::<days::day12::Day12>(),
+];
+
+/// Looks up a day in the registry.
+pub fn day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
+
+/// Runs a day on its input, printing answers, timings and the summary.
+///
+/// The input defaults to `dayXX.txt` in the input directory and can be
+/// overridden with `--input <path>` or `--input -` for stdin.
+/// Input errors are reported on stderr and turned into a failure exit code.
+pub fn run<S: Solution>() -> ExitCode {
+    let source = match InputSource::from_args(S::DAY, env::args().skip(1)) {
+        Ok(source) => source,
+        Err(e) => {
+            eprintln!("{}", e);
+            eprintln!("Usage: day{:02} [--input <path> | -]", S::DAY);
+            return ExitCode::from(2);
+        }
+    };
+
+    let raw = match source.read() {
+        Ok(raw) => raw,
+        Err(e) => {
+            eprintln!("Day {:02}: {}", S::DAY, e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let start = Instant::now();
+    let input = match S::parse(&raw) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Day {:02}: {}: {}", S::DAY, source, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut total = start.elapsed();
+
+    println!("Day {:02} Results: ⭐⭐", S::DAY);
+    println!("Parse: took {:?}", total);
+
+    let start = Instant::now();
+    let result1 = S::part1(&input);
+    let duration1 = start.elapsed();
+    println!("Part 1: {} (took {:?})", result1, duration1);
+    total += duration1;
+
+    let start = Instant::now();
+    if let Some(result2) = S::part2(&input) {
+        let duration2 = start.elapsed();
+        println!("Part 2: {} (took {:?})", result2, duration2);
+        total += duration2;
+    }
+
+    println!("Total: {:?}", total);
+
+    println!("\n--- Résumé des solutions ---");
+    for (i, summary) in S::SUMMARY.iter().enumerate() {
+        println!("Part {}: {}", i + 1, summary);
+    }
+    ExitCode::SUCCESS
 }
@@ -0,0 +1,457 @@
+//! Exact linear algebra over the rationals.
+//!
+//! Entries are `i128` fractions kept in lowest terms, so row reduction never loses
+//! precision; an intermediate value that does not fit panics instead of wrapping.
+
+use std::fmt;
+use std::ops::{Add, Div, Index, IndexMut, Mul, Neg, Sub};
+
+fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// A fraction in lowest terms, with a positive denominator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rational {
+    num: i128,
+    den: i128,
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational { num: 0, den: 1 };
+    pub const ONE: Rational = Rational { num: 1, den: 1 };
+
+    /// `num / den`, reduced; panics if `den` is zero.
+    pub fn new(num: i128, den: i128) -> Self {
+        assert!(den != 0, "zero denominator");
+        let g = gcd(num, den).max(1) * den.signum();
+        Rational {
+            num: num / g,
+            den: den / g,
+        }
+    }
+
+    pub fn numerator(self) -> i128 {
+        self.num
+    }
+
+    pub fn denominator(self) -> i128 {
+        self.den
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.num == 0
+    }
+
+    /// The value as an integer, if it is one.
+    pub fn to_integer(self) -> Option<i128> {
+        (self.den == 1).then_some(self.num)
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(n: i64) -> Self {
+        Rational {
+            num: n as i128,
+            den: 1,
+        }
+    }
+}
+
+fn checked(value: Option<i128>) -> i128 {
+    value.expect("rational overflow")
+}
+
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, other: Rational) -> Rational {
+        // Scaling by the lcm of the denominators keeps intermediate values small
+        let g = gcd(self.den, other.den);
+        let (a, b) = (self.den / g, other.den / g);
+        let num = checked(
+            checked(self.num.checked_mul(b)).checked_add(checked(other.num.checked_mul(a))),
+        );
+        Rational::new(num, checked(self.den.checked_mul(b)))
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        Rational {
+            num: -self.num,
+            den: self.den,
+        }
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, other: Rational) -> Rational {
+        self + -other
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, other: Rational) -> Rational {
+        // Cross-reduce first so that products stay in lowest terms
+        let g1 = gcd(self.num, other.den).max(1);
+        let g2 = gcd(other.num, self.den).max(1);
+        Rational::new(
+            checked((self.num / g1).checked_mul(other.num / g2)),
+            checked((self.den / g2).checked_mul(other.den / g1)),
+        )
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+
+    fn div(self, other: Rational) -> Rational {
+        assert!(!other.is_zero(), "division by zero");
+        self * Rational::new(other.den, other.num)
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.den {
+            1 => write!(f, "{}", self.num),
+            den => write!(f, "{}/{}", self.num, den),
+        }
+    }
+}
+
+/// A dense matrix of rationals, stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Matrix {
+    cells: Vec<Rational>,
+    rows: usize,
+    cols: usize,
+}
+
+impl Matrix {
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Matrix {
+            cells: vec![Rational::ZERO; rows * cols],
+            rows,
+            cols,
+        }
+    }
+
+    /// Builds a matrix from its rows, which must all have `cols` entries.
+    ///
+    /// The width is given rather than taken from the first row, so that a matrix
+    /// without rows still has the right number of columns.
+    pub fn from_rows<R, T>(cols: usize, rows: impl IntoIterator<Item = R>) -> Self
+    where
+        R: IntoIterator<Item = T>,
+        T: Into<Rational>,
+    {
+        let mut cells = Vec::new();
+        let mut count = 0;
+        for row in rows {
+            let start = cells.len();
+            cells.extend(row.into_iter().map(Into::into));
+            assert_eq!(cells.len() - start, cols, "length of row {}", count);
+            count += 1;
+        }
+        Matrix {
+            cells,
+            rows: count,
+            cols,
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn row(&self, row: usize) -> &[Rational] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        for col in 0..self.cols {
+            self.cells.swap(a * self.cols + col, b * self.cols + col);
+        }
+    }
+
+    /// Reduces the matrix to reduced row echelon form, returning the pivot columns.
+    ///
+    /// Every pivot is 1 and is the only non-zero entry of its column; the rows
+    /// past the pivots are all zero.
+    pub fn row_reduce(&mut self) -> Vec<usize> {
+        let mut pivots = Vec::new();
+        for col in 0..self.cols {
+            let row = pivots.len();
+            let Some(pivot_row) = (row..self.rows).find(|&r| !self[(r, col)].is_zero()) else {
+                continue;
+            };
+            self.swap_rows(row, pivot_row);
+
+            let pivot = self[(row, col)];
+            for c in col..self.cols {
+                self[(row, c)] = self[(row, c)] / pivot;
+            }
+            for r in (0..self.rows).filter(|&r| r != row) {
+                let factor = self[(r, col)];
+                if factor.is_zero() {
+                    continue;
+                }
+                for c in col..self.cols {
+                    self[(r, c)] = self[(r, c)] - factor * self[(row, c)];
+                }
+            }
+
+            pivots.push(col);
+            if pivots.len() == self.rows {
+                break;
+            }
+        }
+        pivots
+    }
+
+    /// Number of linearly independent rows.
+    pub fn rank(&self) -> usize {
+        self.clone().row_reduce().len()
+    }
+
+    /// A basis of the vectors `x` with `self * x = 0`, one vector per free column.
+    pub fn null_space(&self) -> Vec<Vec<Rational>> {
+        let mut reduced = self.clone();
+        let pivots = reduced.row_reduce();
+        (0..self.cols)
+            .filter(|col| !pivots.contains(col))
+            .map(|free| {
+                let mut vector = vec![Rational::ZERO; self.cols];
+                vector[free] = Rational::ONE;
+                for (row, &pivot) in pivots.iter().enumerate() {
+                    vector[pivot] = -reduced[(row, free)];
+                }
+                vector
+            })
+            .collect()
+    }
+
+    /// `self * x`.
+    pub fn mul_vec(&self, x: &[Rational]) -> Vec<Rational> {
+        assert_eq!(x.len(), self.cols, "vector length");
+        (0..self.rows)
+            .map(|row| {
+                self.row(row)
+                    .iter()
+                    .zip(x)
+                    .fold(Rational::ZERO, |sum, (&a, &b)| sum + a * b)
+            })
+            .collect()
+    }
+}
+
+impl Index<(usize, usize)> for Matrix {
+    type Output = Rational;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Rational {
+        assert!(
+            row < self.rows && col < self.cols,
+            "({}, {}) out of bounds",
+            row,
+            col
+        );
+        &self.cells[row * self.cols + col]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Rational {
+        assert!(
+            row < self.rows && col < self.cols,
+            "({}, {}) out of bounds",
+            row,
+            col
+        );
+        &mut self.cells[row * self.cols + col]
+    }
+}
+
+/// Every solution of a linear system `a * x = b`, as the pivot variables in terms of
+/// the free ones.
+#[derive(Clone, Debug)]
+pub struct Solutions {
+    /// Reduced augmented matrix `[a | b]`.
+    reduced: Matrix,
+    pivots: Vec<usize>,
+    free: Vec<usize>,
+    /// Each pivot row scaled to integers: (common denominator, right-hand side,
+    /// coefficients of the free variables).
+    scaled: Vec<(i128, i128, Vec<i128>)>,
+}
+
+impl Solutions {
+    /// Solves `a * x = b`, returning `None` if the system has no solution.
+    pub fn of(a: &Matrix, b: &[Rational]) -> Option<Self> {
+        assert_eq!(b.len(), a.rows, "right-hand side length");
+        let mut reduced = Matrix::zeros(a.rows, a.cols + 1);
+        for row in 0..a.rows {
+            for col in 0..a.cols {
+                reduced[(row, col)] = a[(row, col)];
+            }
+            reduced[(row, a.cols)] = b[row];
+        }
+        let pivots = reduced.row_reduce();
+        // A pivot in the right-hand side column means 0 = 1
+        if pivots.last() == Some(&a.cols) {
+            return None;
+        }
+        let free: Vec<usize> = (0..a.cols).filter(|col| !pivots.contains(col)).collect();
+        let scaled = (0..pivots.len())
+            .map(|row| {
+                let terms = || free.iter().chain([&a.cols]).map(|&col| reduced[(row, col)]);
+                let den = terms().fold(1, |lcm, x| {
+                    checked((lcm / gcd(lcm, x.den)).checked_mul(x.den))
+                });
+                let scale = |x: Rational| checked(x.num.checked_mul(den / x.den));
+                let coefficients = free.iter().map(|&col| scale(reduced[(row, col)]));
+                (den, scale(reduced[(row, a.cols)]), coefficients.collect())
+            })
+            .collect();
+        Some(Solutions {
+            reduced,
+            pivots,
+            free,
+            scaled,
+        })
+    }
+
+    /// Variables that can take any value, in increasing order.
+    pub fn free_variables(&self) -> &[usize] {
+        &self.free
+    }
+
+    /// The solution with the free variables set to `values`.
+    pub fn solution(&self, values: &[Rational]) -> Vec<Rational> {
+        assert_eq!(values.len(), self.free.len(), "one value per free variable");
+        let rhs = self.reduced.cols - 1;
+        let mut x = vec![Rational::ZERO; rhs];
+        for (&col, &value) in self.free.iter().zip(values) {
+            x[col] = value;
+        }
+        for (row, &pivot) in self.pivots.iter().enumerate() {
+            x[pivot] = self
+                .free
+                .iter()
+                .fold(self.reduced[(row, rhs)], |sum, &col| {
+                    sum - self.reduced[(row, col)] * x[col]
+                });
+        }
+        x
+    }
+
+    /// The solution with the free variables set to `values`, if it is all integers.
+    pub fn integer_solution(&self, values: &[i64]) -> Option<Vec<i128>> {
+        let mut x = vec![0; self.reduced.cols - 1];
+        self.integer_solution_into(values, &mut x).then_some(x)
+    }
+
+    /// Like `integer_solution`, but writes the solution into `x` and returns whether it
+    /// is all integers, in which case `x` is complete.
+    ///
+    /// Works on the integer-scaled rows without allocating, which makes it cheap
+    /// enough to call while enumerating values of the free variables.
+    pub fn integer_solution_into(&self, values: &[i64], x: &mut [i128]) -> bool {
+        assert_eq!(values.len(), self.free.len(), "one value per free variable");
+        assert_eq!(x.len(), self.reduced.cols - 1, "one entry per variable");
+        for (&col, &value) in self.free.iter().zip(values) {
+            x[col] = value as i128;
+        }
+        for (&pivot, (den, rhs, coefficients)) in self.pivots.iter().zip(&self.scaled) {
+            let num = coefficients.iter().zip(values).fold(*rhs, |sum, (&c, &v)| {
+                checked(sum.checked_sub(checked(c.checked_mul(v as i128))))
+            });
+            if num % den != 0 {
+                return false;
+            }
+            x[pivot] = num / den;
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn r(num: i128, den: i128) -> Rational {
+        Rational::new(num, den)
+    }
+
+    #[test]
+    fn test_rational() {
+        assert_eq!(r(4, -6), r(-2, 3));
+        assert_eq!(r(1, 2) + r(1, 3), r(5, 6));
+        assert_eq!(r(1, 2) - r(1, 2), Rational::ZERO);
+        assert_eq!(r(2, 3) * r(9, 4), r(3, 2));
+        assert_eq!(r(2, 3) / r(4, 9), r(3, 2));
+        assert_eq!(r(6, 3).to_integer(), Some(2));
+        assert_eq!(r(1, 3).to_integer(), None);
+        assert_eq!(r(-7, 2).to_string(), "-7/2");
+        // Products that would overflow i64 stay exact
+        let big = Rational::from(i64::MAX);
+        assert_eq!((big * big / big).to_integer(), Some(i64::MAX as i128));
+    }
+
+    #[test]
+    fn test_row_reduce() {
+        let mut m = Matrix::from_rows(3, [[1i64, 2, 3], [2, 4, 6], [1, 0, 1]]);
+        assert_eq!(m.rank(), 2);
+        assert_eq!(m.row_reduce(), [0, 1]);
+        assert_eq!(
+            m,
+            Matrix::from_rows(3, [[1i64, 0, 1], [0, 1, 1], [0, 0, 0]])
+        );
+
+        let null = m.null_space();
+        assert_eq!(null, [vec![r(-1, 1), r(-1, 1), Rational::ONE]]);
+        assert!(m.mul_vec(&null[0]).iter().all(|x| x.is_zero()));
+    }
+
+    #[test]
+    fn test_solutions() {
+        // x + y = 3, y + z = 5
+        let a = Matrix::from_rows(3, [[1i64, 1, 0], [0, 1, 1]]);
+        let b = [3.into(), 5.into()];
+        let solutions = Solutions::of(&a, &b).unwrap();
+        assert_eq!(solutions.free_variables(), [2]);
+        assert_eq!(solutions.integer_solution(&[1]), Some(vec![-1, 4, 1]));
+
+        // 2x = 1 has no integer solution, x + y = 1 and x + y = 2 none at all
+        let half = Solutions::of(&Matrix::from_rows(1, [[2i64]]), &[1.into()]).unwrap();
+        assert_eq!(half.integer_solution(&[]), None);
+        assert_eq!(half.solution(&[]), [r(1, 2)]);
+        let a = Matrix::from_rows(2, [[1i64, 1], [1, 1]]);
+        assert!(Solutions::of(&a, &[1.into(), 2.into()]).is_none());
+    }
+
+    #[test]
+    fn test_no_equations() {
+        let a = Matrix::from_rows(2, Vec::<[i64; 2]>::new());
+        assert_eq!((a.rows(), a.cols(), a.rank()), (0, 2, 0));
+        assert_eq!(a.null_space().len(), 2);
+        let solutions = Solutions::of(&a, &[]).unwrap();
+        assert_eq!(solutions.free_variables(), [0, 1]);
+        assert_eq!(solutions.integer_solution(&[3, 4]), Some(vec![3, 4]));
+    }
+}